@@ -38,6 +38,10 @@ export const DEVNET_USDC_MINT = new PublicKey('4zMMC9srt5Ri5X14GAgXhaHii3GnPAEER
 export const SUBSCRIPTION_CONFIG_PDA = new PublicKey('7pobVEtga9fSLngK3EUgtu45WuqrrnTgBsHPAc4cYRQN');
 export const ALERT_REGISTRY_PDA = new PublicKey('ErwSC32EUrF9PNcbqeT1Hdn85x2VhHVMfXUFTqyD5uWs');
 export const PUBLISHER_REGISTRY_PDA = new PublicKey('3H8MprBvoDiuKRCoUmYw3x9WipWu8nU9uRWffEzEEzmx');
+export const CHANNEL_CATALOG_PDA = PublicKey.findProgramAddressSync(
+  [Buffer.from('channel_catalog')],
+  SUBSCRIPTION_PROGRAM_ID
+)[0];
 
 // Configuration
 const PRICE_PER_ALERT = 20000; // 0.02 USDC (6 decimals)
@@ -254,7 +258,7 @@ export class SolanaClient {
    */
   async createSubscriber(
     ownerKeypair: Keypair,
-    channels: number[]
+    channelBitmap: number
   ): Promise<string> {
     const [subscriberPDA] = this.getSubscriberPDA(ownerKeypair.publicKey);
     const [subscriberVault] = this.getSubscriberVaultPDA(ownerKeypair.publicKey);
@@ -262,9 +266,8 @@ export class SolanaClient {
     // Build instruction
     // Anchor discriminator for create_subscriber = sha256("global:create_subscriber")[0..8]
     const discriminator = Buffer.from([2, 10, 181, 63, 185, 231, 150, 156]);
-    const channelsBuffer = Buffer.alloc(4 + channels.length);
-    channelsBuffer.writeUInt32LE(channels.length, 0);
-    channels.forEach((c, i) => channelsBuffer.writeUInt8(c, 4 + i));
+    const channelsBuffer = Buffer.alloc(4);
+    channelsBuffer.writeUInt32LE(channelBitmap >>> 0, 0);
     
     const data = Buffer.concat([discriminator, channelsBuffer]);
     
@@ -273,6 +276,7 @@ export class SolanaClient {
       programId: SUBSCRIPTION_PROGRAM_ID,
       keys: [
         { pubkey: SUBSCRIPTION_CONFIG_PDA, isSigner: false, isWritable: true },
+        { pubkey: CHANNEL_CATALOG_PDA, isSigner: false, isWritable: false },
         { pubkey: subscriberPDA, isSigner: false, isWritable: true },
         { pubkey: subscriberVault, isSigner: false, isWritable: true },
         { pubkey: DEVNET_USDC_MINT, isSigner: false, isWritable: false },
@@ -316,19 +320,9 @@ export class SolanaClient {
     // Anchor discriminator for create_subscriber = sha256("global:create_subscriber")[0..8]
     const discriminator = Buffer.from([2, 10, 181, 63, 185, 231, 150, 156]);
     
-    // Channels as Vec<u8> - convert bitmap to bytes
-    const channelBytes: number[] = [];
-    for (let i = 0; i < 4; i++) {
-      channelBytes.push((channelBitmap >> (i * 8)) & 0xff);
-    }
-    // Remove trailing zeros
-    while (channelBytes.length > 1 && channelBytes[channelBytes.length - 1] === 0) {
-      channelBytes.pop();
-    }
-    
-    const channelsBuffer = Buffer.alloc(4 + channelBytes.length);
-    channelsBuffer.writeUInt32LE(channelBytes.length, 0);
-    channelBytes.forEach((b, i) => channelsBuffer.writeUInt8(b, 4 + i));
+    // Channels as u32 bitmap of ChannelCatalog indices
+    const channelsBuffer = Buffer.alloc(4);
+    channelsBuffer.writeUInt32LE(channelBitmap >>> 0, 0);
     
     const data = Buffer.concat([discriminator, channelsBuffer]);
     
@@ -337,6 +331,7 @@ export class SolanaClient {
       programId: SUBSCRIPTION_PROGRAM_ID,
       keys: [
         { pubkey: SUBSCRIPTION_CONFIG_PDA, isSigner: false, isWritable: true },
+        { pubkey: CHANNEL_CATALOG_PDA, isSigner: false, isWritable: false },
         { pubkey: subscriberPDA, isSigner: false, isWritable: true },
         { pubkey: subscriberVault, isSigner: false, isWritable: true },
         { pubkey: DEVNET_USDC_MINT, isSigner: false, isWritable: false },
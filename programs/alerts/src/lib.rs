@@ -1,7 +1,64 @@
+// Anchor 0.31's `#[program]` expands to IDL account handlers that call the
+// deprecated `AccountInfo::realloc`; this crate doesn't call it itself.
+#![allow(deprecated)]
+
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::{hash, hashv};
+use publisher_registry::{Publisher, PublisherDelegate, PublisherRegistry};
+use publisher_registry::program::PublisherRegistry as PublisherRegistryProgram;
+use subscription_registry::{ChannelCatalog, DeliveryReceipt, Distributor, Subscriber};
 
 declare_id!("BsMVJwatabfvQMtkJxUuS5jYvmrk1j8VUVFv5sG9595t");
 
+const ALERT_SPACE: usize = 8 + 4 + 64 + 4 + 32 + 1 + 32 + 32 + 8 + 1 + 1 + 8 + 1 + 8 + 8
+    + 1 + 32 + 32 + 32 + 8 + 8 + 1;
+
+/// SHA256 of an alert ID, used as its PDA seed (IDs may exceed 32 bytes)
+pub fn alert_id_hash(alert_id: &str) -> [u8; 32] {
+    hash(alert_id.as_bytes()).to_bytes()
+}
+
+/// Address of the alert registered by publisher `identity` under `alert_id`
+pub fn find_alert_address(identity: &Pubkey, alert_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"alert", identity.as_ref(), &alert_id_hash(alert_id)],
+        &ID,
+    )
+}
+
+/// Maximum Merkle proof depth accepted by `verify_inclusion`
+pub const MAX_PROOF_DEPTH: usize = 32;
+
+/// Merkle leaf for a batched alert. Variable-length fields are hashed first
+/// so the encoding is unambiguous; the 0x00 prefix separates leaves from
+/// internal nodes.
+pub fn batch_leaf(
+    alert_id: &str,
+    channel: &str,
+    content_hash: &[u8; 32],
+    priority: u8,
+    impact_score: u8,
+) -> [u8; 32] {
+    hashv(&[
+        &[0x00],
+        &alert_id_hash(alert_id),
+        &hash(channel.as_bytes()).to_bytes(),
+        content_hash,
+        &[priority, impact_score],
+    ]).to_bytes()
+}
+
+/// Fold a proof into a root. Sibling pairs are hashed in sorted order with
+/// a 0x01 prefix, so proofs carry no left/right flags. A level with an odd
+/// node count promotes its last node unchanged, so that node's proof skips
+/// the level.
+pub fn merkle_root_from_proof(leaf: [u8; 32], proof: &[[u8; 32]]) -> [u8; 32] {
+    proof.iter().fold(leaf, |node, sibling| {
+        let (left, right) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+        hashv(&[&[0x01], &left, &right]).to_bytes()
+    })
+}
+
 /// Agent News Wire - Alert Registry Program
 /// 
 /// Stores alert hashes on-chain for proof of existence and timing.
@@ -12,17 +69,48 @@ pub mod alert_registry {
     use super::*;
 
     /// Initialize the alert registry
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+    pub fn initialize(ctx: Context<Initialize>, min_reputation: u16) -> Result<()> {
         let registry = &mut ctx.accounts.registry;
         registry.authority = ctx.accounts.authority.key();
         registry.total_alerts = 0;
         registry.bump = ctx.bumps.registry;
+        registry.min_reputation = min_reputation;
+        registry.total_batched_leaves = 0;
+        
+        msg!("Alert registry initialized: min_reputation={}", min_reputation);
+        Ok(())
+    }
+
+    /// Set the minimum publisher reputation required to register alerts
+    pub fn set_min_reputation(ctx: Context<UpdateRegistry>, min_reputation: u16) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.min_reputation = min_reputation;
+        
+        msg!("Minimum publisher reputation set to {}", min_reputation);
+        Ok(())
+    }
+
+    /// Create the hash-chained log for a catalog channel (permissionless)
+    pub fn initialize_channel_log(ctx: Context<InitializeChannelLog>, channel: String) -> Result<()> {
+        require!(
+            ctx.accounts.channel_catalog.active_index(&channel).is_some(),
+            ErrorCode::UnknownChannel
+        );
+        
+        let log = &mut ctx.accounts.channel_log;
+        log.channel = channel.clone();
+        log.sequence = 0;
+        log.head = [0; 32];
+        log.updated_at = Clock::get()?.unix_timestamp;
+        log.bump = ctx.bumps.channel_log;
         
-        msg!("Alert registry initialized");
+        msg!("Channel log initialized: {}", channel);
         Ok(())
     }
 
-    /// Register a new alert on-chain (called by authorized publishers)
+    /// Register a new alert on-chain (called by active, staked publishers)
+    ///
+    /// Advances the channel's log and stamps the alert with its sequence.
     pub fn register_alert(
         ctx: Context<RegisterAlert>,
         alert_id: String,
@@ -35,26 +123,75 @@ pub mod alert_registry {
         require!(channel.len() <= 32, ErrorCode::ChannelNameTooLong);
         require!(priority <= 3, ErrorCode::InvalidPriority);
         require!(impact_score <= 10, ErrorCode::InvalidImpactScore);
+        let now = Clock::get()?.unix_timestamp;
+        let reputation = ctx.accounts.publisher_account
+            .current_reputation(&ctx.accounts.publisher_registry.reputation, now);
+        require!(reputation >= ctx.accounts.registry.min_reputation, ErrorCode::ReputationTooLow);
+        let channel_index = ctx.accounts.channel_catalog.active_index(&channel)
+            .ok_or(ErrorCode::UnknownChannel)?;
+        
+        // The signer is either the publisher's owner or one of its delegates
+        if ctx.accounts.publisher.key() != ctx.accounts.publisher_account.owner {
+            let delegate = ctx.accounts.delegate.as_ref()
+                .ok_or(ErrorCode::UnauthorizedPublisher)?;
+            require!(delegate.allows(channel_index, now), ErrorCode::DelegateNotAllowed);
+        }
+        
+        claim_content(
+            &mut ctx.accounts.content_index,
+            content_hash,
+            ctx.accounts.alert.key(),
+            ctx.accounts.publisher_account.identity,
+            now,
+            ctx.bumps.content_index,
+        )?;
         
         let alert = &mut ctx.accounts.alert;
         alert.alert_id = alert_id.clone();
         alert.channel = channel;
+        alert.channel_index = channel_index;
         alert.content_hash = content_hash;
-        alert.publisher = ctx.accounts.publisher.key();
-        alert.timestamp = Clock::get()?.unix_timestamp;
+        alert.publisher = ctx.accounts.publisher_account.identity;
+        alert.timestamp = now;
         alert.priority = priority;
         alert.impact_score = impact_score;
         alert.delivery_count = 0;
+        alert.first_reporter = true;
+        alert.upvote_weight = 0;
+        alert.downvote_weight = 0;
+        alert.status = AlertStatus::Active;
+        alert.supersedes = Pubkey::default();
+        alert.superseded_by = Pubkey::default();
+        alert.reason_hash = [0; 32];
+        alert.status_updated_at = now;
+        alert.seq = ctx.accounts.channel_log.advance(&content_hash, now)?;
         alert.bump = ctx.bumps.alert;
         
         let registry = &mut ctx.accounts.registry;
         registry.total_alerts += 1;
         
+        // Credit the publisher's reputation, signing as the registry PDA
+        let seeds = &[b"registry".as_ref(), &[registry.bump]];
+        let signer = &[&seeds[..]];
+        let cpi_accounts = publisher_registry::cpi::accounts::AlertRegistryUpdate {
+            registry: ctx.accounts.publisher_registry.to_account_info(),
+            publisher: ctx.accounts.publisher_account.to_account_info(),
+            alert_registry: registry.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.publisher_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        publisher_registry::cpi::record_registered_alert(cpi_ctx)?;
+        
         msg!("Alert registered: {}", alert_id);
         Ok(())
     }
 
-    /// Record an alert delivery (for proof)
+    /// Record an alert delivery (for proof, called by authorized distributor)
+    ///
+    /// `subscriber` is the subscription registry `Subscriber` account. The
+    /// matching delivery receipt proves the charge, and the publisher's share
+    /// of it is accrued in the publisher registry. The delivery record is
+    /// keyed by the receipt, so each charge is accrued exactly once.
     pub fn record_delivery(
         ctx: Context<RecordDelivery>,
         subscriber: Pubkey,
@@ -68,21 +205,335 @@ pub mod alert_registry {
         delivery.timestamp = Clock::get()?.unix_timestamp;
         delivery.bump = ctx.bumps.delivery;
         
+        // Accrue the publisher's earnings, signing as the registry PDA
+        let registry = &ctx.accounts.registry;
+        let seeds = &[b"registry".as_ref(), &[registry.bump]];
+        let signer = &[&seeds[..]];
+        let cpi_accounts = publisher_registry::cpi::accounts::AlertDeliveryUpdate {
+            registry: ctx.accounts.publisher_registry.to_account_info(),
+            publisher: ctx.accounts.publisher_account.to_account_info(),
+            alert_registry: registry.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.publisher_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        publisher_registry::cpi::record_alert_delivery(cpi_ctx, ctx.accounts.delivery_receipt.amount_charged)?;
+        
         msg!("Delivery recorded for alert {} to {}", alert.alert_id, subscriber);
         Ok(())
     }
 
+    /// Up- or down-vote a delivered alert (subscriber owner only, once per paid receipt)
+    ///
+    /// The subscriber must hold a delivery receipt for the alert's content;
+    /// the vote is weighted by the amount it was charged and feeds the
+    /// publisher's rating sub-score.
+    pub fn rate_alert(ctx: Context<RateAlert>, upvote: bool) -> Result<()> {
+        let weight = ctx.accounts.delivery_receipt.amount_charged;
+        require!(weight > 0, ErrorCode::UnpaidDelivery);
+        
+        let alert = &mut ctx.accounts.alert;
+        if upvote {
+            alert.upvote_weight = alert.upvote_weight
+                .checked_add(weight)
+                .ok_or(ErrorCode::Overflow)?;
+        } else {
+            alert.downvote_weight = alert.downvote_weight
+                .checked_add(weight)
+                .ok_or(ErrorCode::Overflow)?;
+        }
+        
+        let rating = &mut ctx.accounts.rating;
+        rating.alert = alert.key();
+        rating.subscriber = ctx.accounts.subscriber.key();
+        rating.upvote = upvote;
+        rating.weight = weight;
+        rating.rated_at = Clock::get()?.unix_timestamp;
+        rating.bump = ctx.bumps.rating;
+        
+        // Feed the publisher's reputation, signing as the registry PDA
+        let registry = &ctx.accounts.registry;
+        let seeds = &[b"registry".as_ref(), &[registry.bump]];
+        let signer = &[&seeds[..]];
+        let cpi_accounts = publisher_registry::cpi::accounts::AlertRegistryUpdate {
+            registry: ctx.accounts.publisher_registry.to_account_info(),
+            publisher: ctx.accounts.publisher_account.to_account_info(),
+            alert_registry: registry.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.publisher_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        publisher_registry::cpi::record_alert_rating(cpi_ctx, weight, upvote)?;
+        
+        msg!("Alert {} rated: upvote={}, weight={}", alert.alert_id, upvote, weight);
+        Ok(())
+    }
+
+    /// Report a batch that re-anchors another publisher's registered content.
+    ///
+    /// The content index rejects a second `Alert` with the same hash, so
+    /// copies can only land in an `AlertBatch`. Permissionless: the reporter
+    /// proves the batch contains `original`'s content hash, anchored strictly
+    /// after it, and the batch publisher is slashed with a bounty paid to the
+    /// reporter. Each (batch, content hash) pair can be reported once.
+    pub fn report_duplicate(
+        ctx: Context<ReportDuplicate>,
+        alert_id: String,
+        channel: String,
+        priority: u8,
+        impact_score: u8,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        require!(proof.len() <= MAX_PROOF_DEPTH, ErrorCode::ProofTooLong);
+        let original = &ctx.accounts.original;
+        let batch = &ctx.accounts.batch;
+        
+        require_keys_neq!(original.publisher, batch.publisher, ErrorCode::SamePublisher);
+        require!(original.timestamp < batch.anchored_at, ErrorCode::NotLaterAlert);
+        let leaf = batch_leaf(&alert_id, &channel, &original.content_hash, priority, impact_score);
+        require!(merkle_root_from_proof(leaf, &proof) == batch.merkle_root, ErrorCode::NotInBatch);
+        
+        let report = &mut ctx.accounts.report;
+        report.original = original.key();
+        report.duplicate = batch.key();
+        report.publisher = batch.publisher;
+        report.reporter = ctx.accounts.reporter.key();
+        report.reported_at = Clock::get()?.unix_timestamp;
+        report.bump = ctx.bumps.report;
+        
+        // Slash the copying publisher, signing as the registry PDA
+        let registry = &ctx.accounts.registry;
+        let seeds = &[b"registry".as_ref(), &[registry.bump]];
+        let signer = &[&seeds[..]];
+        let cpi_accounts = publisher_registry::cpi::accounts::SlashDuplicate {
+            registry: ctx.accounts.publisher_registry.to_account_info(),
+            publisher: ctx.accounts.publisher_account.to_account_info(),
+            stake_vault: ctx.accounts.stake_vault.to_account_info(),
+            treasury: ctx.accounts.treasury.to_account_info(),
+            reporter_token_account: ctx.accounts.reporter_token_account.to_account_info(),
+            alert_registry: registry.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.publisher_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        publisher_registry::cpi::slash_duplicate(cpi_ctx, batch.key())?;
+        
+        msg!("Duplicate reported: batch {} copies {}", batch.key(), original.alert_id);
+        Ok(())
+    }
+
+    /// Commit a Merkle root over many alerts in one account (called by
+    /// active, staked publishers or unrestricted delegates)
+    ///
+    /// Leaves are `batch_leaf(alert_id, channel, content_hash, priority,
+    /// impact_score)`; individual alerts are proven with `verify_inclusion`.
+    ///
+    /// Batched alerts only prove existence and timing: their channels are not
+    /// checked against the catalog, their content hashes are not claimed in
+    /// the `ContentIndex`, and they are not appended to any `ChannelLog`.
+    /// `leaf_count` is the publisher's own claim and is tallied separately
+    /// from `total_alerts`.
+    pub fn anchor_batch(
+        ctx: Context<AnchorBatch>,
+        merkle_root: [u8; 32],
+        leaf_count: u32,
+    ) -> Result<()> {
+        require!(leaf_count > 0, ErrorCode::EmptyBatch);
+        let now = Clock::get()?.unix_timestamp;
+        let reputation = ctx.accounts.publisher_account
+            .current_reputation(&ctx.accounts.publisher_registry.reputation, now);
+        require!(reputation >= ctx.accounts.registry.min_reputation, ErrorCode::ReputationTooLow);
+        
+        // Leaf channels can't be checked here, so delegates must be unrestricted
+        if ctx.accounts.publisher.key() != ctx.accounts.publisher_account.owner {
+            let delegate = ctx.accounts.delegate.as_ref()
+                .ok_or(ErrorCode::UnauthorizedPublisher)?;
+            require!(
+                delegate.channels == 0 && delegate.allows(0, now),
+                ErrorCode::DelegateNotAllowed
+            );
+        }
+        
+        let batch = &mut ctx.accounts.batch;
+        batch.publisher = ctx.accounts.publisher_account.identity;
+        batch.merkle_root = merkle_root;
+        batch.leaf_count = leaf_count;
+        batch.anchored_at = now;
+        batch.bump = ctx.bumps.batch;
+        
+        let registry = &mut ctx.accounts.registry;
+        registry.total_batched_leaves = registry.total_batched_leaves
+            .saturating_add(leaf_count as u64);
+        
+        msg!("Alert batch anchored: {} leaves", leaf_count);
+        Ok(())
+    }
+
+    /// Check that an alert is included in an anchored batch (view function)
+    pub fn verify_inclusion(
+        ctx: Context<VerifyInclusion>,
+        alert_id: String,
+        channel: String,
+        content_hash: [u8; 32],
+        priority: u8,
+        impact_score: u8,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<bool> {
+        require!(proof.len() <= MAX_PROOF_DEPTH, ErrorCode::ProofTooLong);
+        
+        let leaf = batch_leaf(&alert_id, &channel, &content_hash, priority, impact_score);
+        let included = merkle_root_from_proof(leaf, &proof) == ctx.accounts.batch.merkle_root;
+        
+        msg!("Batch inclusion: {}", if included { "VALID" } else { "INVALID" });
+        Ok(included)
+    }
+
+    /// Publish a corrected version of an alert (original publisher owner only)
+    ///
+    /// The correction is a new alert on the same channel, linked to the
+    /// original through `supersedes` / `superseded_by`. It is gated and
+    /// credited exactly like `register_alert`.
+    pub fn correct_alert(
+        ctx: Context<CorrectAlert>,
+        alert_id: String,
+        content_hash: [u8; 32],
+        priority: u8,
+        impact_score: u8,
+    ) -> Result<()> {
+        require!(alert_id.len() <= 64, ErrorCode::AlertIdTooLong);
+        require!(priority <= 3, ErrorCode::InvalidPriority);
+        require!(impact_score <= 10, ErrorCode::InvalidImpactScore);
+        
+        let now = Clock::get()?.unix_timestamp;
+        let reputation = ctx.accounts.publisher_account
+            .current_reputation(&ctx.accounts.publisher_registry.reputation, now);
+        require!(reputation >= ctx.accounts.registry.min_reputation, ErrorCode::ReputationTooLow);
+        
+        let original = &mut ctx.accounts.original;
+        require!(original.status == AlertStatus::Active, ErrorCode::AlertNotActive);
+        require!(original.content_hash != content_hash, ErrorCode::UnchangedContent);
+        
+        claim_content(
+            &mut ctx.accounts.content_index,
+            content_hash,
+            ctx.accounts.correction.key(),
+            original.publisher,
+            now,
+            ctx.bumps.content_index,
+        )?;
+        
+        let correction = &mut ctx.accounts.correction;
+        correction.alert_id = alert_id.clone();
+        correction.channel = original.channel.clone();
+        correction.channel_index = original.channel_index;
+        correction.content_hash = content_hash;
+        correction.publisher = original.publisher;
+        correction.timestamp = now;
+        correction.priority = priority;
+        correction.impact_score = impact_score;
+        correction.delivery_count = 0;
+        correction.first_reporter = original.first_reporter;
+        correction.upvote_weight = 0;
+        correction.downvote_weight = 0;
+        correction.status = AlertStatus::Active;
+        correction.supersedes = original.key();
+        correction.superseded_by = Pubkey::default();
+        correction.reason_hash = [0; 32];
+        correction.status_updated_at = now;
+        correction.seq = ctx.accounts.channel_log.advance(&content_hash, now)?;
+        correction.bump = ctx.bumps.correction;
+        
+        original.status = AlertStatus::Superseded;
+        original.superseded_by = correction.key();
+        original.status_updated_at = now;
+        
+        let registry = &mut ctx.accounts.registry;
+        registry.total_alerts += 1;
+        
+        // Credit the publisher as for any registered alert, signing as the registry PDA
+        let seeds = &[b"registry".as_ref(), &[registry.bump]];
+        let signer = &[&seeds[..]];
+        let cpi_accounts = publisher_registry::cpi::accounts::AlertRegistryUpdate {
+            registry: ctx.accounts.publisher_registry.to_account_info(),
+            publisher: ctx.accounts.publisher_account.to_account_info(),
+            alert_registry: registry.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.publisher_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        publisher_registry::cpi::record_registered_alert(cpi_ctx)?;
+        
+        msg!("Alert {} corrected by {}", original.alert_id, alert_id);
+        Ok(())
+    }
+
+    /// Retract an alert, recording the hash of the retraction reason
+    /// (original publisher owner only)
+    pub fn retract_alert(ctx: Context<RetractAlert>, reason_hash: [u8; 32]) -> Result<()> {
+        let alert = &mut ctx.accounts.alert;
+        require!(alert.status != AlertStatus::Retracted, ErrorCode::AlertNotActive);
+        
+        alert.status = AlertStatus::Retracted;
+        alert.reason_hash = reason_hash;
+        alert.status_updated_at = Clock::get()?.unix_timestamp;
+        
+        msg!("Alert retracted: {}", alert.alert_id);
+        Ok(())
+    }
+
+    /// Return the alert that first registered `content_hash` (view function)
+    pub fn lookup_content(ctx: Context<LookupContent>, content_hash: [u8; 32]) -> Result<Pubkey> {
+        let index = &ctx.accounts.content_index;
+        require!(index.content_hash == content_hash, ErrorCode::ContentMismatch);
+        
+        msg!("Content registered by alert {}", index.alert);
+        Ok(index.alert)
+    }
+
     /// Verify alert existence (view function, no state change)
+    ///
+    /// Reports whether `expected_hash` matches this alert and, if so,
+    /// whether it is still the current version.
     pub fn verify_alert(
         ctx: Context<VerifyAlert>,
         expected_hash: [u8; 32],
-    ) -> Result<bool> {
+    ) -> Result<AlertVerification> {
         let alert = &ctx.accounts.alert;
-        let matches = alert.content_hash == expected_hash;
+        let result = if alert.content_hash != expected_hash {
+            AlertVerification::Invalid
+        } else {
+            match alert.status {
+                AlertStatus::Active => AlertVerification::Current,
+                AlertStatus::Superseded => AlertVerification::Superseded,
+                AlertStatus::Retracted => AlertVerification::Retracted,
+            }
+        };
         
-        msg!("Alert verification: {}", if matches { "VALID" } else { "INVALID" });
-        Ok(matches)
+        msg!("Alert verification: {:?}", result);
+        Ok(result)
+    }
+}
+
+// === Helpers ===
+
+/// Record `alert` as the owner of `content_hash`, failing with the original
+/// alert's address if the hash has already been registered.
+fn claim_content(
+    index: &mut Account<ContentIndex>,
+    content_hash: [u8; 32],
+    alert: Pubkey,
+    publisher: Pubkey,
+    now: i64,
+    bump: u8,
+) -> Result<()> {
+    if index.alert != Pubkey::default() {
+        msg!("Content already registered by alert {}", index.alert);
+        return Err(error!(ErrorCode::DuplicateContent).with_pubkeys((index.alert, alert)));
     }
+    
+    index.content_hash = content_hash;
+    index.alert = alert;
+    index.publisher = publisher;
+    index.registered_at = now;
+    index.bump = bump;
+    Ok(())
 }
 
 // === Account Structures ===
@@ -90,20 +541,86 @@ pub mod alert_registry {
 #[account]
 pub struct AlertRegistry {
     pub authority: Pubkey,
-    pub total_alerts: u64,
+    pub total_alerts: u64,          // Individually registered alerts (one per content hash)
     pub bump: u8,
+    pub min_reputation: u16,        // Minimum publisher reputation to register alerts
+    pub total_batched_leaves: u64,  // Leaves claimed by anchored batches (unverified)
 }
 
 #[account]
 pub struct Alert {
     pub alert_id: String,           // Max 64 chars
     pub channel: String,            // Max 32 chars
+    pub channel_index: u8,          // Bit index in the subscription ChannelCatalog
     pub content_hash: [u8; 32],     // SHA256 of alert content
-    pub publisher: Pubkey,
+    pub publisher: Pubkey,          // Publisher identity (stable across ownership transfers)
     pub timestamp: i64,
     pub priority: u8,               // 0=low, 1=medium, 2=high, 3=critical
     pub impact_score: u8,           // 0-10
     pub delivery_count: u64,
+    pub first_reporter: bool,       // Always set: the content index admits one alert per hash
+    pub upvote_weight: u64,         // Sum of amounts paid by upvoting subscribers
+    pub downvote_weight: u64,
+    pub status: AlertStatus,
+    pub supersedes: Pubkey,         // Alert this one corrects (default = none)
+    pub superseded_by: Pubkey,      // Correction of this alert (default = none)
+    pub reason_hash: [u8; 32],      // Hash of the retraction reason
+    pub status_updated_at: i64,
+    pub seq: u64,                   // Position in the channel log
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AlertStatus {
+    Active,
+    Superseded,
+    Retracted,
+}
+
+/// Result of `verify_alert`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AlertVerification {
+    Invalid,                        // Hash does not match this alert
+    Current,                        // Matches the current version
+    Superseded,                     // Matches a version that has been corrected
+    Retracted,                      // Matches a retracted alert
+}
+
+#[account]
+pub struct ContentIndex {
+    pub content_hash: [u8; 32],
+    pub alert: Pubkey,              // First alert registered with this hash
+    pub publisher: Pubkey,          // Publisher identity
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct ChannelLog {
+    pub channel: String,            // Max 32 chars
+    pub sequence: u64,              // Sequence of the latest alert (0 = empty)
+    pub head: [u8; 32],             // H(prev_head || content_hash) of the latest alert
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl ChannelLog {
+    /// Append `content_hash` to the chain and return its sequence number.
+    pub fn advance(&mut self, content_hash: &[u8; 32], now: i64) -> Result<u64> {
+        self.sequence = self.sequence.checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+        self.head = hashv(&[&self.head, content_hash]).to_bytes();
+        self.updated_at = now;
+        Ok(self.sequence)
+    }
+}
+
+#[account]
+pub struct AlertBatch {
+    pub publisher: Pubkey,          // Publisher identity
+    pub merkle_root: [u8; 32],
+    pub leaf_count: u32,
+    pub anchored_at: i64,
     pub bump: u8,
 }
 
@@ -115,6 +632,26 @@ pub struct AlertDelivery {
     pub bump: u8,
 }
 
+#[account]
+pub struct AlertRating {
+    pub alert: Pubkey,
+    pub subscriber: Pubkey,         // subscription_registry::Subscriber account
+    pub upvote: bool,
+    pub weight: u64,                // Amount charged for the delivery
+    pub rated_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct DuplicateReport {
+    pub original: Pubkey,
+    pub duplicate: Pubkey,          // Batch that re-anchored the content
+    pub publisher: Pubkey,          // Publisher of the batch
+    pub reporter: Pubkey,
+    pub reported_at: i64,
+    pub bump: u8,
+}
+
 // === Contexts ===
 
 #[derive(Accounts)]
@@ -122,7 +659,7 @@ pub struct Initialize<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + 32 + 8 + 1,
+        space = 8 + 32 + 8 + 1 + 2 + 8,
         seeds = [b"registry"],
         bump
     )]
@@ -135,7 +672,46 @@ pub struct Initialize<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(alert_id: String)]
+pub struct UpdateRegistry<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry.bump,
+        has_one = authority
+    )]
+    pub registry: Account<'info, AlertRegistry>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(channel: String)]
+pub struct InitializeChannelLog<'info> {
+    /// Channel catalog in the subscription registry
+    #[account(
+        seeds = [b"channel_catalog"],
+        bump = channel_catalog.bump,
+        seeds::program = subscription_registry::ID
+    )]
+    pub channel_catalog: Account<'info, ChannelCatalog>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 4 + 32 + 8 + 32 + 8 + 1,
+        seeds = [b"channel_log", channel.as_bytes()],
+        bump
+    )]
+    pub channel_log: Account<'info, ChannelLog>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(alert_id: String, channel: String, content_hash: [u8; 32])]
 pub struct RegisterAlert<'info> {
     #[account(
         mut,
@@ -144,45 +720,417 @@ pub struct RegisterAlert<'info> {
     )]
     pub registry: Account<'info, AlertRegistry>,
     
+    /// Channel catalog in the subscription registry
+    #[account(
+        seeds = [b"channel_catalog"],
+        bump = channel_catalog.bump,
+        seeds::program = subscription_registry::ID
+    )]
+    pub channel_catalog: Account<'info, ChannelCatalog>,
+    
+    #[account(
+        mut,
+        seeds = [b"channel_log", channel.as_bytes()],
+        bump = channel_log.bump
+    )]
+    pub channel_log: Account<'info, ChannelLog>,
+    
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = publisher_registry.bump,
+        seeds::program = publisher_registry::ID
+    )]
+    pub publisher_registry: Account<'info, PublisherRegistry>,
+    
+    /// Publisher record in the publisher registry
+    #[account(
+        mut,
+        seeds = [b"publisher", publisher_account.owner.as_ref()],
+        bump = publisher_account.bump,
+        seeds::program = publisher_registry::ID,
+        constraint = publisher_account.active @ ErrorCode::UnauthorizedPublisher,
+        constraint = !publisher_account.slashed @ ErrorCode::UnauthorizedPublisher
+    )]
+    pub publisher_account: Account<'info, Publisher>,
+    
+    /// Delegate authorization, required when the signer is not the owner
+    #[account(
+        seeds = [b"delegate", publisher_account.key().as_ref(), publisher.key().as_ref()],
+        bump = delegate.bump,
+        seeds::program = publisher_registry::ID
+    )]
+    pub delegate: Option<Account<'info, PublisherDelegate>>,
+    
+    /// Publisher owner, or a delegate key when `delegate` is supplied
+    #[account(mut)]
+    pub publisher: Signer<'info>,
+    
+    /// Namespaced by publisher identity and the hash of `alert_id`
     #[account(
         init,
         payer = publisher,
-        space = 8 + 4 + 64 + 4 + 32 + 32 + 32 + 8 + 1 + 1 + 8 + 1,
-        seeds = [b"alert", alert_id.as_bytes()],
+        space = ALERT_SPACE,
+        seeds = [
+            b"alert",
+            publisher_account.identity.as_ref(),
+            alert_id_hash(&alert_id).as_ref()
+        ],
         bump
     )]
     pub alert: Account<'info, Alert>,
     
-    #[account(mut)]
-    pub publisher: Signer<'info>,
+    /// Global index entry for `content_hash`; rejects re-registration
+    #[account(
+        init_if_needed,
+        payer = publisher,
+        space = 8 + 32 + 32 + 32 + 8 + 1,
+        seeds = [b"content", content_hash.as_ref()],
+        bump
+    )]
+    pub content_index: Account<'info, ContentIndex>,
     
+    pub publisher_program: Program<'info, PublisherRegistryProgram>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
+#[instruction(subscriber: Pubkey)]
 pub struct RecordDelivery<'info> {
+    #[account(
+        seeds = [b"registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, AlertRegistry>,
+    
     #[account(mut)]
     pub alert: Account<'info, Alert>,
     
+    /// Settled subscription charge for this alert
+    #[account(
+        seeds = [b"delivery", subscriber.as_ref(), alert.content_hash.as_ref()],
+        bump = delivery_receipt.bump,
+        seeds::program = subscription_registry::ID
+    )]
+    pub delivery_receipt: Account<'info, DeliveryReceipt>,
+    
+    #[account(
+        mut,
+        seeds = [b"publisher_registry"],
+        bump = publisher_registry.bump,
+        seeds::program = publisher_registry::ID
+    )]
+    pub publisher_registry: Account<'info, PublisherRegistry>,
+    
+    /// Publisher record of the alert's publisher
+    #[account(
+        mut,
+        seeds = [b"publisher", publisher_account.owner.as_ref()],
+        bump = publisher_account.bump,
+        seeds::program = publisher_registry::ID,
+        constraint = publisher_account.identity == alert.publisher @ ErrorCode::PublisherMismatch
+    )]
+    pub publisher_account: Account<'info, Publisher>,
+    
+    /// One delivery record per subscription delivery receipt
     #[account(
         init,
         payer = distributor,
         space = 8 + 32 + 32 + 8 + 1,
-        seeds = [
-            b"delivery",
-            alert.key().as_ref(),
-            &Clock::get()?.unix_timestamp.to_le_bytes()
-        ],
+        seeds = [b"delivery", delivery_receipt.key().as_ref()],
         bump
     )]
     pub delivery: Account<'info, AlertDelivery>,
     
+    /// Distributor allow-list entry in the subscription registry
+    #[account(
+        seeds = [b"distributor", distributor.key().as_ref()],
+        bump = distributor_account.bump,
+        seeds::program = subscription_registry::ID,
+        constraint = distributor_account.active @ ErrorCode::UnauthorizedDistributor
+    )]
+    pub distributor_account: Account<'info, Distributor>,
+    
     #[account(mut)]
     pub distributor: Signer<'info>,
     
+    pub publisher_program: Program<'info, PublisherRegistryProgram>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RateAlert<'info> {
+    #[account(
+        seeds = [b"registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, AlertRegistry>,
+    
+    #[account(mut)]
+    pub alert: Account<'info, Alert>,
+    
+    /// Subscriber account in the subscription registry, owned by the signer
+    #[account(
+        seeds = [b"subscriber", owner.key().as_ref()],
+        bump = subscriber.bump,
+        seeds::program = subscription_registry::ID
+    )]
+    pub subscriber: Account<'info, Subscriber>,
+    
+    /// Settled subscription charge for this alert
+    #[account(
+        seeds = [b"delivery", subscriber.key().as_ref(), alert.content_hash.as_ref()],
+        bump = delivery_receipt.bump,
+        seeds::program = subscription_registry::ID
+    )]
+    pub delivery_receipt: Account<'info, DeliveryReceipt>,
+    
+    /// One rating per paid delivery receipt
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + 32 + 32 + 1 + 8 + 8 + 1,
+        seeds = [b"rating", delivery_receipt.key().as_ref()],
+        bump
+    )]
+    pub rating: Account<'info, AlertRating>,
+    
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = publisher_registry.bump,
+        seeds::program = publisher_registry::ID
+    )]
+    pub publisher_registry: Account<'info, PublisherRegistry>,
+    
+    /// Publisher record of the alert's publisher
+    #[account(
+        mut,
+        seeds = [b"publisher", publisher_account.owner.as_ref()],
+        bump = publisher_account.bump,
+        seeds::program = publisher_registry::ID,
+        constraint = publisher_account.identity == alert.publisher @ ErrorCode::PublisherMismatch
+    )]
+    pub publisher_account: Account<'info, Publisher>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub publisher_program: Program<'info, PublisherRegistryProgram>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct ReportDuplicate<'info> {
+    #[account(
+        seeds = [b"registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, AlertRegistry>,
+    
+    pub original: Account<'info, Alert>,
+    
+    /// Batch that re-anchored `original`'s content
+    pub batch: Account<'info, AlertBatch>,
+    
+    /// One report per (batch, content hash)
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + 32 + 32 + 32 + 32 + 8 + 1,
+        seeds = [b"duplicate", batch.key().as_ref(), original.content_hash.as_ref()],
+        bump
+    )]
+    pub report: Account<'info, DuplicateReport>,
+    
+    /// CHECK: Validated by the publisher registry
+    pub publisher_registry: AccountInfo<'info>,
+    
+    /// Publisher record of the batch's publisher
+    #[account(
+        mut,
+        seeds = [b"publisher", publisher_account.owner.as_ref()],
+        bump = publisher_account.bump,
+        seeds::program = publisher_registry::ID,
+        constraint = publisher_account.identity == batch.publisher @ ErrorCode::PublisherMismatch
+    )]
+    pub publisher_account: Account<'info, Publisher>,
+    
+    /// CHECK: Publisher stake vault, validated by the publisher registry
+    #[account(mut)]
+    pub stake_vault: AccountInfo<'info>,
+    
+    /// CHECK: Publisher registry treasury, validated by the publisher registry
+    #[account(mut)]
+    pub treasury: AccountInfo<'info>,
+    
+    /// CHECK: Reporter's USDC token account, validated by the publisher registry
+    #[account(mut)]
+    pub reporter_token_account: AccountInfo<'info>,
+    
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+    
+    pub publisher_program: Program<'info, PublisherRegistryProgram>,
+    /// CHECK: SPL token program, validated by the publisher registry
+    pub token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(merkle_root: [u8; 32])]
+pub struct AnchorBatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, AlertRegistry>,
+    
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = publisher_registry.bump,
+        seeds::program = publisher_registry::ID
+    )]
+    pub publisher_registry: Account<'info, PublisherRegistry>,
+    
+    /// Publisher record in the publisher registry
+    #[account(
+        seeds = [b"publisher", publisher_account.owner.as_ref()],
+        bump = publisher_account.bump,
+        seeds::program = publisher_registry::ID,
+        constraint = publisher_account.active @ ErrorCode::UnauthorizedPublisher,
+        constraint = !publisher_account.slashed @ ErrorCode::UnauthorizedPublisher
+    )]
+    pub publisher_account: Account<'info, Publisher>,
+    
+    /// Delegate authorization, required when the signer is not the owner
+    #[account(
+        seeds = [b"delegate", publisher_account.key().as_ref(), publisher.key().as_ref()],
+        bump = delegate.bump,
+        seeds::program = publisher_registry::ID
+    )]
+    pub delegate: Option<Account<'info, PublisherDelegate>>,
+    
+    /// Publisher owner, or a delegate key when `delegate` is supplied
+    #[account(mut)]
+    pub publisher: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = publisher,
+        space = 8 + 32 + 32 + 4 + 8 + 1,
+        seeds = [b"alert_batch", publisher_account.identity.as_ref(), merkle_root.as_ref()],
+        bump
+    )]
+    pub batch: Account<'info, AlertBatch>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyInclusion<'info> {
+    pub batch: Account<'info, AlertBatch>,
+}
+
+#[derive(Accounts)]
+#[instruction(alert_id: String, content_hash: [u8; 32])]
+pub struct CorrectAlert<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, AlertRegistry>,
+    
+    #[account(
+        mut,
+        constraint = original.publisher == publisher_account.identity @ ErrorCode::PublisherMismatch
+    )]
+    pub original: Account<'info, Alert>,
+    
+    #[account(
+        mut,
+        seeds = [b"channel_log", original.channel.as_bytes()],
+        bump = channel_log.bump
+    )]
+    pub channel_log: Account<'info, ChannelLog>,
+    
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = publisher_registry.bump,
+        seeds::program = publisher_registry::ID
+    )]
+    pub publisher_registry: Account<'info, PublisherRegistry>,
+    
+    /// Publisher record owned by the signer
+    #[account(
+        mut,
+        seeds = [b"publisher", publisher.key().as_ref()],
+        bump = publisher_account.bump,
+        seeds::program = publisher_registry::ID,
+        constraint = publisher_account.active @ ErrorCode::UnauthorizedPublisher,
+        constraint = !publisher_account.slashed @ ErrorCode::UnauthorizedPublisher
+    )]
+    pub publisher_account: Account<'info, Publisher>,
+    
+    #[account(mut)]
+    pub publisher: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = publisher,
+        space = ALERT_SPACE,
+        seeds = [
+            b"alert",
+            publisher_account.identity.as_ref(),
+            alert_id_hash(&alert_id).as_ref()
+        ],
+        bump
+    )]
+    pub correction: Account<'info, Alert>,
+    
+    /// Global index entry for the corrected `content_hash`
+    #[account(
+        init_if_needed,
+        payer = publisher,
+        space = 8 + 32 + 32 + 32 + 8 + 1,
+        seeds = [b"content", content_hash.as_ref()],
+        bump
+    )]
+    pub content_index: Account<'info, ContentIndex>,
+    
+    pub publisher_program: Program<'info, PublisherRegistryProgram>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RetractAlert<'info> {
+    #[account(
+        mut,
+        constraint = alert.publisher == publisher_account.identity @ ErrorCode::PublisherMismatch
+    )]
+    pub alert: Account<'info, Alert>,
+    
+    /// Publisher record owned by the signer
+    #[account(
+        seeds = [b"publisher", publisher.key().as_ref()],
+        bump = publisher_account.bump,
+        seeds::program = publisher_registry::ID
+    )]
+    pub publisher_account: Account<'info, Publisher>,
+    
+    pub publisher: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(content_hash: [u8; 32])]
+pub struct LookupContent<'info> {
+    #[account(
+        seeds = [b"content", content_hash.as_ref()],
+        bump = content_index.bump
+    )]
+    pub content_index: Account<'info, ContentIndex>,
+}
+
 #[derive(Accounts)]
 pub struct VerifyAlert<'info> {
     pub alert: Account<'info, Alert>,
@@ -202,4 +1150,93 @@ pub enum ErrorCode {
     InvalidImpactScore,
     #[msg("Unauthorized publisher")]
     UnauthorizedPublisher,
+    #[msg("Unauthorized distributor")]
+    UnauthorizedDistributor,
+    #[msg("Channel is not in the channel catalog")]
+    UnknownChannel,
+    #[msg("Publisher reputation below registry minimum")]
+    ReputationTooLow,
+    #[msg("Alerts do not share a content hash")]
+    ContentMismatch,
+    #[msg("Alerts have the same publisher")]
+    SamePublisher,
+    #[msg("Duplicate batch must be anchored after the original alert")]
+    NotLaterAlert,
+    #[msg("Delivery receipt has no charge to weight a rating")]
+    UnpaidDelivery,
+    #[msg("Arithmetic overflow")]
+    Overflow,
+    #[msg("Publisher account does not match the alert's publisher")]
+    PublisherMismatch,
+    #[msg("Delegate has expired or is not allowed on this channel")]
+    DelegateNotAllowed,
+    #[msg("Alert has already been corrected or retracted")]
+    AlertNotActive,
+    #[msg("Correction must change the content hash")]
+    UnchangedContent,
+    #[msg("Batch must contain at least one alert")]
+    EmptyBatch,
+    #[msg("Merkle proof too long")]
+    ProofTooLong,
+    #[msg("Content hash already registered by another alert")]
+    DuplicateContent,
+    #[msg("Proof does not place the original content in the batch")]
+    NotInBatch,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
+        let (left, right) = if left <= right { (left, right) } else { (right, left) };
+        hashv(&[&[0x01], &left, &right]).to_bytes()
+    }
+
+    fn leaf(alert_id: &str) -> [u8; 32] {
+        batch_leaf(alert_id, "sec/filings", &hash(alert_id.as_bytes()).to_bytes(), 1, 50)
+    }
+
+    #[test]
+    fn batch_leaf_commits_to_every_field() {
+        let content_hash = [7u8; 32];
+        let base = batch_leaf("a-1", "sec/filings", &content_hash, 1, 50);
+        assert_ne!(base, batch_leaf("a-2", "sec/filings", &content_hash, 1, 50));
+        assert_ne!(base, batch_leaf("a-1", "crypto/defi", &content_hash, 1, 50));
+        assert_ne!(base, batch_leaf("a-1", "sec/filings", &[8u8; 32], 1, 50));
+        assert_ne!(base, batch_leaf("a-1", "sec/filings", &content_hash, 2, 50));
+        assert_ne!(base, batch_leaf("a-1", "sec/filings", &content_hash, 1, 51));
+    }
+
+    #[test]
+    fn single_leaf_tree_is_its_own_root() {
+        let a = leaf("a");
+        assert_eq!(merkle_root_from_proof(a, &[]), a);
+    }
+
+    #[test]
+    fn odd_node_count_promotes_last_node() {
+        let (a, b, c) = (leaf("a"), leaf("b"), leaf("c"));
+        let ab = node(a, b);
+        let root = node(ab, c);
+        
+        assert_eq!(merkle_root_from_proof(a, &[b, c]), root);
+        assert_eq!(merkle_root_from_proof(b, &[a, c]), root);
+        assert_eq!(merkle_root_from_proof(c, &[ab]), root);
+    }
+
+    #[test]
+    fn tampered_proof_is_rejected() {
+        let (a, b, c, d) = (leaf("a"), leaf("b"), leaf("c"), leaf("d"));
+        let cd = node(c, d);
+        let root = node(node(a, b), cd);
+        assert_eq!(merkle_root_from_proof(a, &[b, cd]), root);
+        
+        let mut tampered = b;
+        tampered[0] ^= 1;
+        assert_ne!(merkle_root_from_proof(a, &[tampered, cd]), root);
+        assert_ne!(merkle_root_from_proof(a, &[cd, b]), root);
+        assert_ne!(merkle_root_from_proof(a, &[b]), root);
+        assert_ne!(merkle_root_from_proof(leaf("e"), &[b, cd]), root);
+    }
 }
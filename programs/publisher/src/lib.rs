@@ -1,8 +1,51 @@
+// Anchor 0.31's `#[program]` expands to IDL account handlers that call the
+// deprecated `AccountInfo::realloc`; this crate doesn't call it itself.
+#![allow(deprecated)]
+
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
+use anchor_lang::solana_program::hash::{hash, hashv};
+use anchor_spl::token::{CloseAccount, Mint, Token, TokenAccount, Transfer, close_account, transfer};
 
 declare_id!("H3DAhavhTEom9RsZkpKTYonZcfDQ7pqoH6SXrUAAsHNc");
 
+/// Alert registry program, whose `registry` PDA is the only permitted
+/// caller of the CPI reputation hooks.
+pub const ALERT_REGISTRY_PROGRAM_ID: Pubkey = pubkey!("BsMVJwatabfvQMtkJxUuS5jYvmrk1j8VUVFv5sG9595t");
+
+/// Maximum alert accounts cited as evidence in a slash proposal
+pub const MAX_EVIDENCE_ALERTS: usize = 4;
+
+/// Rejection timestamps kept per publisher for spam detection
+pub const MAX_TRACKED_REJECTIONS: usize = 16;
+
+/// Spam defaults: more than 10 rejections in 24h proposes a 10% slash
+pub const DEFAULT_SPAM_REJECTION_LIMIT: u8 = 10;
+pub const DEFAULT_SPAM_WINDOW: i64 = 86_400;
+pub const DEFAULT_SPAM_SLASH_BPS: u16 = 1000;
+
+/// Default reputation model: +1.0 accuracy per accepted alert, -2.0 per
+/// rejection, idle accuracy above the 50.0 baseline halves its excess every
+/// 30 days; 10 deliveries per alert counts as full engagement
+pub const DEFAULT_REPUTATION: ReputationParams = ReputationParams {
+    gain: 10,
+    loss: 20,
+    half_life: 30 * 86_400,
+    floor: 0,
+    cap: 1000,
+    initial: 500,
+    accuracy_weight: 4000,
+    engagement_weight: 2000,
+    timeliness_weight: 2000,
+    rating_weight: 2000,
+    target_deliveries: 10,
+};
+
+const PUBLISHER_SPACE: usize = 8 + 32 + 32 + 32 + 4 + 64 + 4 + 200 + 8 + 8 + 8 + 4 + 4 + 2 + 8
+    + 2 + 2 + 2 + 2 + 8 + 8 + 8 + 8 + 8 + 8 + 8 * MAX_TRACKED_REJECTIONS + 1 + 8 + 8 + 8 + 8 + 1 + 1 + 1 + 1;
+
+const SLASH_PROPOSAL_SPACE: usize =
+    8 + 32 + 4 + 8 + 4 + 128 + 32 + 4 + 32 * MAX_EVIDENCE_ALERTS + 8 + 8 + 32 + 1 + 8 + 1;
+
 /// Agent News Wire - Publisher Registry Program
 /// 
 /// Manages publisher registration, reputation scoring, staking,
@@ -17,7 +60,13 @@ pub mod publisher_registry {
         ctx: Context<Initialize>,
         min_stake: u64,
         publisher_share_bps: u16,
+        unbonding_period: i64,
+        challenge_period: i64,
     ) -> Result<()> {
+        require!(publisher_share_bps <= 10000, ErrorCode::InvalidBps);
+        require!(unbonding_period >= 0, ErrorCode::InvalidUnbondingPeriod);
+        require!(challenge_period >= 0, ErrorCode::InvalidChallengePeriod);
+        
         let registry = &mut ctx.accounts.registry;
         registry.authority = ctx.accounts.authority.key();
         registry.usdc_mint = ctx.accounts.usdc_mint.key();
@@ -26,9 +75,98 @@ pub mod publisher_registry {
         registry.total_publishers = 0;
         registry.total_payouts = 0;
         registry.bump = ctx.bumps.registry;
+        registry.unbonding_period = unbonding_period;
+        registry.challenge_period = challenge_period;
+        registry.treasury = ctx.accounts.treasury.key();
+        registry.duplicate_slash_bps = 0;
+        registry.reporter_bounty_bps = 0;
+        registry.spam_rejection_limit = DEFAULT_SPAM_REJECTION_LIMIT;
+        registry.spam_window = DEFAULT_SPAM_WINDOW;
+        registry.spam_slash_bps = DEFAULT_SPAM_SLASH_BPS;
+        registry.reputation = DEFAULT_REPUTATION;
+        registry.total_unclaimed = 0;
+        
+        msg!("Publisher registry initialized: min_stake={}, share={}bps, unbonding={}s, challenge={}s", 
+            min_stake, publisher_share_bps, unbonding_period, challenge_period);
+        Ok(())
+    }
+
+    /// Set the unstake cooldown in seconds (authority only)
+    pub fn set_unbonding_period(
+        ctx: Context<UpdateRegistry>,
+        unbonding_period: i64,
+    ) -> Result<()> {
+        require!(unbonding_period >= 0, ErrorCode::InvalidUnbondingPeriod);
+        
+        let registry = &mut ctx.accounts.registry;
+        registry.unbonding_period = unbonding_period;
+        
+        msg!("Unbonding period set to {}s", unbonding_period);
+        Ok(())
+    }
+
+    /// Set the slash appeal window in seconds (authority only)
+    pub fn set_challenge_period(
+        ctx: Context<UpdateRegistry>,
+        challenge_period: i64,
+    ) -> Result<()> {
+        require!(challenge_period >= 0, ErrorCode::InvalidChallengePeriod);
+        
+        let registry = &mut ctx.accounts.registry;
+        registry.challenge_period = challenge_period;
+        
+        msg!("Challenge period set to {}s", challenge_period);
+        Ok(())
+    }
+
+    /// Configure automatic spam detection (authority only)
+    pub fn set_spam_params(
+        ctx: Context<UpdateRegistry>,
+        spam_rejection_limit: u8,
+        spam_window: i64,
+        spam_slash_bps: u16,
+    ) -> Result<()> {
+        require!(
+            (spam_rejection_limit as usize) < MAX_TRACKED_REJECTIONS,
+            ErrorCode::InvalidSpamParams
+        );
+        require!(spam_window > 0, ErrorCode::InvalidSpamParams);
+        require!(spam_slash_bps <= 10000, ErrorCode::InvalidBps);
+        
+        let registry = &mut ctx.accounts.registry;
+        registry.spam_rejection_limit = spam_rejection_limit;
+        registry.spam_window = spam_window;
+        registry.spam_slash_bps = spam_slash_bps;
+        
+        msg!("Spam params set: >{} rejections in {}s slashes {}bps", 
+            spam_rejection_limit, spam_window, spam_slash_bps);
+        Ok(())
+    }
+
+    /// Replace the reputation model parameters (authority only)
+    pub fn set_reputation_params(
+        ctx: Context<UpdateRegistry>,
+        params: ReputationParams,
+    ) -> Result<()> {
+        require!(
+            params.floor <= params.initial && params.initial <= params.cap && params.cap <= 1000,
+            ErrorCode::InvalidReputationParams
+        );
+        require!(params.half_life >= 0, ErrorCode::InvalidReputationParams);
+        require!(params.target_deliveries > 0, ErrorCode::InvalidReputationParams);
+        let total_weight = params.accuracy_weight as u32
+            + params.engagement_weight as u32
+            + params.timeliness_weight as u32
+            + params.rating_weight as u32;
+        require!(total_weight == 10000, ErrorCode::InvalidReputationParams);
+        
+        let registry = &mut ctx.accounts.registry;
+        registry.reputation = params;
         
-        msg!("Publisher registry initialized: min_stake={}, share={}bps", 
-            min_stake, publisher_share_bps);
+        msg!("Reputation params set: +{}/-{}, half_life={}s, range={}..{}, initial={}", 
+            params.gain, params.loss, params.half_life, params.floor, params.cap, params.initial);
+        msg!("Reputation weights: accuracy={}, engagement={}, timeliness={}, rating={}bps", 
+            params.accuracy_weight, params.engagement_weight, params.timeliness_weight, params.rating_weight);
         Ok(())
     }
 
@@ -55,16 +193,36 @@ pub mod publisher_registry {
         
         let publisher = &mut ctx.accounts.publisher;
         publisher.owner = ctx.accounts.owner.key();
+        publisher.identity = ctx.accounts.owner.key();
+        publisher.pending_owner = Pubkey::default();
         publisher.name = name.clone();
         publisher.metadata_uri = metadata_uri;
         publisher.stake = registry.min_stake;
-        publisher.reputation_score = 500; // Start at 50.0 (scaled by 10)
+        publisher.reputation_score = registry.reputation.initial;
+        publisher.reputation_updated_at = Clock::get()?.unix_timestamp;
+        publisher.accuracy_score = registry.reputation.initial;
+        publisher.engagement_score = registry.reputation.initial;
+        publisher.timeliness_score = registry.reputation.initial;
+        publisher.rating_score = registry.reputation.initial;
+        publisher.alerts_registered = 0;
+        publisher.alerts_first_reported = 0;
+        publisher.rating_up_weight = 0;
+        publisher.rating_down_weight = 0;
         publisher.alerts_submitted = 0;
         publisher.alerts_accepted = 0;
         publisher.total_earnings = 0;
+        publisher.unclaimed_earnings = 0;
+        publisher.alerts_delivered = 0;
+        publisher.pending_unstake = 0;
+        publisher.unstake_available_at = 0;
+        publisher.slash_proposal_count = 0;
+        publisher.open_slash_proposals = 0;
+        publisher.recent_rejections = [0; MAX_TRACKED_REJECTIONS];
+        publisher.rejection_cursor = 0;
         publisher.registered_at = Clock::get()?.unix_timestamp;
         publisher.active = true;
         publisher.slashed = false;
+        publisher.suspended = false;
         publisher.bump = ctx.bumps.publisher;
         
         let registry = &mut ctx.accounts.registry;
@@ -74,33 +232,275 @@ pub mod publisher_registry {
         Ok(())
     }
 
-    /// Record alert submission and acceptance
-    pub fn record_alert_submission(
-        ctx: Context<RecordSubmission>,
-        accepted: bool,
+    /// Change the publisher's display name and/or metadata URI (owner only)
+    pub fn update_publisher_profile(
+        ctx: Context<UpdatePublisher>,
+        name: Option<String>,
+        metadata_uri: Option<String>,
+    ) -> Result<()> {
+        let publisher = &mut ctx.accounts.publisher;
+        
+        if let Some(name) = name {
+            require!(name.len() <= 64, ErrorCode::NameTooLong);
+            publisher.name = name;
+        }
+        if let Some(metadata_uri) = metadata_uri {
+            require!(metadata_uri.len() <= 200, ErrorCode::UriTooLong);
+            publisher.metadata_uri = metadata_uri;
+        }
+        
+        msg!("Publisher profile updated: {} ({})", publisher.name, publisher.metadata_uri);
+        Ok(())
+    }
+
+    /// Start moving the publisher to a new owner key (owner only).
+    /// Pass the default pubkey to cancel a pending transfer.
+    pub fn transfer_publisher_ownership(
+        ctx: Context<UpdatePublisher>,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        let publisher = &mut ctx.accounts.publisher;
+        require_keys_neq!(new_owner, publisher.owner, ErrorCode::InvalidNewOwner);
+        
+        publisher.pending_owner = new_owner;
+        
+        msg!("Ownership transfer of {} proposed to {}", publisher.name, new_owner);
+        Ok(())
+    }
+
+    /// Complete an ownership transfer (pending owner only)
+    ///
+    /// The publisher record and stake are moved to PDAs under the new key.
+    /// The old record is left behind as an inert tombstone so the previous
+    /// key cannot register a second publisher with the same identity.
+    pub fn accept_publisher_ownership(ctx: Context<AcceptPublisherOwnership>) -> Result<()> {
+        let previous = &mut ctx.accounts.previous_publisher;
+        require!(previous.open_slash_proposals == 0, ErrorCode::SlashPending);
+        
+        let previous_owner = previous.owner;
+        let new_owner = ctx.accounts.new_owner.key();
+        
+        // Move the whole stake into the new vault, then close the old one
+        let seeds = &[
+            b"stake_vault",
+            previous_owner.as_ref(),
+            &[ctx.bumps.previous_stake_vault],
+        ];
+        let signer = &[&seeds[..]];
+        
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.previous_stake_vault.to_account_info(),
+            to: ctx.accounts.stake_vault.to_account_info(),
+            authority: ctx.accounts.previous_stake_vault.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        transfer(cpi_ctx, ctx.accounts.previous_stake_vault.amount)?;
+        
+        let cpi_accounts = CloseAccount {
+            account: ctx.accounts.previous_stake_vault.to_account_info(),
+            destination: ctx.accounts.previous_owner.to_account_info(),
+            authority: ctx.accounts.previous_stake_vault.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        close_account(cpi_ctx)?;
+        
+        // Copy the record, keeping reputation and earnings history
+        let mut migrated = (**previous).clone();
+        migrated.owner = new_owner;
+        migrated.pending_owner = Pubkey::default();
+        migrated.bump = ctx.bumps.publisher;
+        ctx.accounts.publisher.set_inner(migrated);
+        
+        previous.owner = Pubkey::default();
+        previous.pending_owner = Pubkey::default();
+        previous.stake = 0;
+        previous.pending_unstake = 0;
+        previous.unclaimed_earnings = 0;
+        previous.active = false;
+        
+        let publisher = &ctx.accounts.publisher;
+        emit!(PublisherOwnershipTransferred {
+            publisher: publisher.key(),
+            identity: publisher.identity,
+            previous_owner,
+            new_owner,
+        });
+        
+        msg!("Publisher {} transferred from {} to {}", publisher.name, previous_owner, new_owner);
+        Ok(())
+    }
+
+    /// Authorize a hot key to register alerts for this publisher (owner only)
+    ///
+    /// Delegates are bound to the publisher record, so they do not survive
+    /// an ownership transfer.
+    pub fn add_delegate(
+        ctx: Context<AddDelegate>,
+        delegate: Pubkey,
+        channels: u32,
+        expires_at: i64,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require_keys_neq!(delegate, ctx.accounts.owner.key(), ErrorCode::InvalidDelegate);
+        require!(expires_at == 0 || expires_at > now, ErrorCode::InvalidDelegate);
+        
+        let delegate_account = &mut ctx.accounts.delegate_account;
+        delegate_account.publisher = ctx.accounts.publisher.key();
+        delegate_account.delegate = delegate;
+        delegate_account.channels = channels;
+        delegate_account.expires_at = expires_at;
+        delegate_account.created_at = now;
+        delegate_account.bump = ctx.bumps.delegate_account;
+        
+        msg!("Delegate {} added for {}: channels={:#x}, expires_at={}", 
+            delegate, ctx.accounts.publisher.name, channels, expires_at);
+        Ok(())
+    }
+
+    /// Revoke a delegate key and reclaim its rent (owner only)
+    pub fn revoke_delegate(ctx: Context<RevokeDelegate>) -> Result<()> {
+        msg!("Delegate {} revoked for {}", 
+            ctx.accounts.delegate_account.delegate, ctx.accounts.publisher.name);
+        Ok(())
+    }
+
+    /// Record a rejected alert submission (registry authority only)
+    ///
+    /// Accepted alerts are credited by `record_registered_alert` when they are
+    /// registered. Rejections are tracked in a rolling window. When they
+    /// exceed the registry's spam limit the publisher is deactivated and a
+    /// slash proposal is opened, so `proposal` must be supplied on that call.
+    pub fn record_alert_rejection(ctx: Context<RecordRejection>) -> Result<()> {
+        let registry = &ctx.accounts.registry;
+        let publisher = &mut ctx.accounts.publisher;
+        let now = Clock::get()?.unix_timestamp;
+        apply_submission(publisher, &registry.reputation, false, now);
+        
+        let recent = record_rejection(publisher, now, registry.spam_window);
+        let spam_triggered = publisher.active && recent > registry.spam_rejection_limit;
+        
+        if spam_triggered {
+            publisher.active = false;
+            publisher.suspended = true;
+            
+            let slash_amount = (publisher.stake as u128)
+                .checked_mul(registry.spam_slash_bps as u128)
+                .ok_or(ErrorCode::Overflow)?
+                .checked_div(10000)
+                .ok_or(ErrorCode::Overflow)? as u64;
+            let proposal = ctx.accounts.proposal.as_mut()
+                .ok_or(ErrorCode::SpamProposalRequired)?;
+            open_slash_proposal(
+                proposal,
+                publisher,
+                slash_amount,
+                format!("spam: {} rejections within {}s", recent, registry.spam_window),
+                Vec::new(),
+                now,
+                registry.challenge_period,
+                ctx.bumps.proposal.ok_or(ErrorCode::SpamProposalRequired)?,
+            )?;
+            
+            msg!("Publisher {} deactivated for spam", publisher.name);
+        }
+        require!(
+            spam_triggered || ctx.accounts.proposal.is_none(),
+            ErrorCode::UnexpectedSpamProposal
+        );
+        
+        msg!("Alert rejection recorded: new_reputation={}", publisher.reputation_score);
+        Ok(())
+    }
+
+    /// Record an alert accepted by the alert registry (CPI from alert_registry only)
+    ///
+    /// Registered alerts start out as first reports; `slash_duplicate`
+    /// takes the credit back if one turns out to be a copy.
+    pub fn record_registered_alert(ctx: Context<AlertRegistryUpdate>) -> Result<()> {
+        let publisher = &mut ctx.accounts.publisher;
+        let now = Clock::get()?.unix_timestamp;
+        publisher.alerts_registered = publisher.alerts_registered
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+        publisher.alerts_first_reported = publisher.alerts_first_reported
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+        apply_submission(publisher, &ctx.accounts.registry.reputation, true, now);
+        
+        msg!("Registered alert recorded for {}: new_reputation={}", 
+            publisher.name, publisher.reputation_score);
+        Ok(())
+    }
+
+    /// Record a subscriber rating weighted by the amount it paid
+    /// (CPI from alert_registry only)
+    pub fn record_alert_rating(
+        ctx: Context<AlertRegistryUpdate>,
+        weight: u64,
+        upvote: bool,
     ) -> Result<()> {
         let publisher = &mut ctx.accounts.publisher;
-        publisher.alerts_submitted += 1;
-        
-        if accepted {
-            publisher.alerts_accepted += 1;
-            // Increase reputation for accepted alerts
-            publisher.reputation_score = publisher.reputation_score
-                .saturating_add(10)
-                .min(1000); // Max 100.0
+        if upvote {
+            publisher.rating_up_weight = publisher.rating_up_weight
+                .checked_add(weight)
+                .ok_or(ErrorCode::Overflow)?;
         } else {
-            // Decrease reputation for rejected alerts
-            publisher.reputation_score = publisher.reputation_score
-                .saturating_sub(20)
-                .max(0);
+            publisher.rating_down_weight = publisher.rating_down_weight
+                .checked_add(weight)
+                .ok_or(ErrorCode::Overflow)?;
         }
+        publisher.recompute_reputation(&ctx.accounts.registry.reputation, Clock::get()?.unix_timestamp);
+        
+        msg!("Rating recorded for {}: upvote={}, weight={}, new_reputation={}", 
+            publisher.name, upvote, weight, publisher.reputation_score);
+        Ok(())
+    }
+
+    /// Store the idle-decayed reputation score (permissionless). Decay is
+    /// measured from the publisher's last accept/reject, so refreshing
+    /// never restarts the idle clock.
+    pub fn refresh_reputation(ctx: Context<RefreshReputation>) -> Result<()> {
+        let publisher = &mut ctx.accounts.publisher;
+        publisher.recompute_reputation(&ctx.accounts.registry.reputation, Clock::get()?.unix_timestamp);
+        
+        msg!("Reputation refreshed for {}: {}", publisher.name, publisher.reputation_score);
+        Ok(())
+    }
+
+    /// Record a paid alert delivery and accrue the publisher's share of the
+    /// charge (CPI from alert_registry only, backed by a delivery receipt)
+    pub fn record_alert_delivery(
+        ctx: Context<AlertDeliveryUpdate>,
+        amount_charged: u64,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        let publisher = &mut ctx.accounts.publisher;
+        
+        let publisher_amount = (amount_charged as u128)
+            .checked_mul(registry.publisher_share_bps as u128)
+            .ok_or(ErrorCode::Overflow)?
+            .checked_div(10000)
+            .ok_or(ErrorCode::Overflow)? as u64;
+        
+        publisher.alerts_delivered = publisher.alerts_delivered
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+        publisher.unclaimed_earnings = publisher.unclaimed_earnings
+            .checked_add(publisher_amount)
+            .ok_or(ErrorCode::Overflow)?;
+        registry.total_unclaimed = registry.total_unclaimed
+            .checked_add(publisher_amount)
+            .ok_or(ErrorCode::Overflow)?;
+        publisher.recompute_reputation(&registry.reputation, Clock::get()?.unix_timestamp);
         
-        msg!("Alert submission recorded: accepted={}, new_reputation={}", 
-            accepted, publisher.reputation_score);
+        msg!("Delivery recorded for {}: accrued={}, unclaimed={}", 
+            publisher.name, publisher_amount, publisher.unclaimed_earnings);
         Ok(())
     }
 
-    /// Distribute revenue to publisher for delivered alert
+    /// Pay out accrued earnings to the publisher's USDC token account (authority only)
     pub fn distribute_revenue(
         ctx: Context<DistributeRevenue>,
         amount: u64,
@@ -110,13 +510,8 @@ pub mod publisher_registry {
         
         require!(publisher.active, ErrorCode::PublisherInactive);
         require!(!publisher.slashed, ErrorCode::PublisherSlashed);
-        
-        // Calculate publisher share
-        let publisher_amount = (amount as u128)
-            .checked_mul(registry.publisher_share_bps as u128)
-            .ok_or(ErrorCode::Overflow)?
-            .checked_div(10000)
-            .ok_or(ErrorCode::Overflow)? as u64;
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(amount <= publisher.unclaimed_earnings, ErrorCode::InsufficientEarnings);
         
         // Transfer from revenue pool to publisher
         let registry_key = registry.key();
@@ -134,254 +529,1436 @@ pub mod publisher_registry {
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-        transfer(cpi_ctx, publisher_amount)?;
+        transfer(cpi_ctx, amount)?;
         
+        publisher.unclaimed_earnings = publisher.unclaimed_earnings
+            .checked_sub(amount)
+            .ok_or(ErrorCode::Overflow)?;
         publisher.total_earnings = publisher.total_earnings
-            .checked_add(publisher_amount)
+            .checked_add(amount)
             .ok_or(ErrorCode::Overflow)?;
         
         let registry = &mut ctx.accounts.registry;
         registry.total_payouts = registry.total_payouts
-            .checked_add(publisher_amount)
+            .checked_add(amount)
             .ok_or(ErrorCode::Overflow)?;
+        registry.total_unclaimed = registry.total_unclaimed.saturating_sub(amount);
         
-        msg!("Distributed {} to publisher {}", publisher_amount, publisher.name);
+        msg!("Distributed {} to publisher {}", amount, publisher.name);
         Ok(())
     }
 
-    /// Slash publisher stake for bad behavior
-    pub fn slash_publisher(
-        ctx: Context<SlashPublisher>,
-        slash_amount: u64,
-        reason: String,
-    ) -> Result<()> {
+    /// Send a slashed publisher's unclaimed earnings to the treasury
+    /// (authority only). Slashed publishers can't claim them themselves.
+    pub fn forfeit_earnings(ctx: Context<ForfeitEarnings>) -> Result<()> {
+        let registry = &ctx.accounts.registry;
         let publisher = &mut ctx.accounts.publisher;
         
-        require!(slash_amount <= publisher.stake, ErrorCode::InsufficientStake);
+        require!(publisher.slashed, ErrorCode::NotSlashed);
+        let amount = publisher.unclaimed_earnings;
+        require!(amount > 0, ErrorCode::InsufficientEarnings);
         
-        publisher.stake = publisher.stake.checked_sub(slash_amount)
-            .ok_or(ErrorCode::Overflow)?;
-        publisher.reputation_score = 0;
+        let registry_key = registry.key();
+        let seeds = &[
+            b"revenue_pool",
+            registry_key.as_ref(),
+            &[ctx.bumps.revenue_pool],
+        ];
+        let signer = &[&seeds[..]];
         
-        if publisher.stake == 0 {
-            publisher.slashed = true;
-            publisher.active = false;
-        }
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.revenue_pool.to_account_info(),
+            to: ctx.accounts.treasury.to_account_info(),
+            authority: ctx.accounts.revenue_pool.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        transfer(cpi_ctx, amount)?;
+        
+        publisher.unclaimed_earnings = 0;
+        let registry = &mut ctx.accounts.registry;
+        registry.total_unclaimed = registry.total_unclaimed.saturating_sub(amount);
+        
+        emit!(EarningsForfeited {
+            publisher: publisher.key(),
+            amount,
+        });
+        
+        msg!("Publisher {} forfeited {} to the treasury", publisher.name, amount);
+        Ok(())
+    }
+
+    /// Move the revenue pool's surplus over accrued publisher earnings (the
+    /// infrastructure share of each charge) to the treasury (authority only)
+    pub fn sweep_revenue_pool(ctx: Context<SweepRevenuePool>, amount: u64) -> Result<()> {
+        let registry = &ctx.accounts.registry;
+        let surplus = ctx.accounts.revenue_pool.amount.saturating_sub(registry.total_unclaimed);
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(amount <= surplus, ErrorCode::InsufficientSurplus);
         
-        // Transfer slashed amount to treasury
-        let owner_key = publisher.owner;
+        let registry_key = registry.key();
         let seeds = &[
-            b"stake_vault",
-            owner_key.as_ref(),
-            &[ctx.bumps.stake_vault],
+            b"revenue_pool",
+            registry_key.as_ref(),
+            &[ctx.bumps.revenue_pool],
         ];
         let signer = &[&seeds[..]];
         
         let cpi_accounts = Transfer {
-            from: ctx.accounts.stake_vault.to_account_info(),
+            from: ctx.accounts.revenue_pool.to_account_info(),
             to: ctx.accounts.treasury.to_account_info(),
-            authority: ctx.accounts.stake_vault.to_account_info(),
+            authority: ctx.accounts.revenue_pool.to_account_info(),
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-        transfer(cpi_ctx, slash_amount)?;
+        transfer(cpi_ctx, amount)?;
         
-        msg!("Publisher {} slashed {} for: {}", publisher.name, slash_amount, reason);
+        msg!("Swept {} from revenue pool to treasury ({} surplus left)", amount, surplus - amount);
         Ok(())
     }
 
-    /// Withdraw stake (deactivates publisher)
-    pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+    /// Claim all accrued earnings to the owner's USDC token account
+    pub fn claim_earnings(ctx: Context<ClaimEarnings>) -> Result<()> {
+        let registry = &ctx.accounts.registry;
         let publisher = &mut ctx.accounts.publisher;
         
         require!(!publisher.slashed, ErrorCode::PublisherSlashed);
+        let amount = publisher.unclaimed_earnings;
+        require!(amount > 0, ErrorCode::InsufficientEarnings);
         
-        let stake_amount = publisher.stake;
-        publisher.stake = 0;
-        publisher.active = false;
-        
-        // Transfer stake back to publisher
-        let owner_key = ctx.accounts.owner.key();
+        // Transfer from revenue pool to publisher
+        let registry_key = registry.key();
         let seeds = &[
-            b"stake_vault",
-            owner_key.as_ref(),
-            &[ctx.bumps.stake_vault],
+            b"revenue_pool",
+            registry_key.as_ref(),
+            &[ctx.bumps.revenue_pool],
         ];
         let signer = &[&seeds[..]];
         
         let cpi_accounts = Transfer {
-            from: ctx.accounts.stake_vault.to_account_info(),
+            from: ctx.accounts.revenue_pool.to_account_info(),
             to: ctx.accounts.publisher_token_account.to_account_info(),
-            authority: ctx.accounts.stake_vault.to_account_info(),
+            authority: ctx.accounts.revenue_pool.to_account_info(),
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-        transfer(cpi_ctx, stake_amount)?;
+        transfer(cpi_ctx, amount)?;
+        
+        publisher.unclaimed_earnings = 0;
+        publisher.total_earnings = publisher.total_earnings
+            .checked_add(amount)
+            .ok_or(ErrorCode::Overflow)?;
+        
+        let registry = &mut ctx.accounts.registry;
+        registry.total_payouts = registry.total_payouts
+            .checked_add(amount)
+            .ok_or(ErrorCode::Overflow)?;
+        registry.total_unclaimed = registry.total_unclaimed.saturating_sub(amount);
+        
+        emit!(EarningsClaimed {
+            publisher: publisher.key(),
+            owner: publisher.owner,
+            amount,
+            total_earnings: publisher.total_earnings,
+        });
         
-        msg!("Publisher {} withdrew stake: {}", publisher.name, stake_amount);
+        msg!("Publisher {} claimed {}", publisher.name, amount);
         Ok(())
     }
-}
 
-// === Account Structures ===
+    /// Set the USDC account that receives slashed stake, forfeited earnings
+    /// and revenue pool sweeps (authority only)
+    pub fn set_treasury(ctx: Context<SetTreasury>) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.treasury = ctx.accounts.treasury.key();
+        
+        msg!("Treasury set to {}", registry.treasury);
+        Ok(())
+    }
 
-#[account]
-pub struct PublisherRegistry {
-    pub authority: Pubkey,
-    pub usdc_mint: Pubkey,
-    pub min_stake: u64,             // Minimum USDC stake required
-    pub publisher_share_bps: u16,   // Publisher revenue share (5000 = 50%)
-    pub total_publishers: u64,
-    pub total_payouts: u64,
-    pub bump: u8,
-}
+    /// Configure permissionless duplicate-content slashing (authority only).
+    /// A zero `duplicate_slash_bps` disables it.
+    pub fn set_duplicate_slash_params(
+        ctx: Context<UpdateRegistry>,
+        duplicate_slash_bps: u16,
+        reporter_bounty_bps: u16,
+    ) -> Result<()> {
+        require!(duplicate_slash_bps <= 10000, ErrorCode::InvalidBps);
+        require!(reporter_bounty_bps <= 10000, ErrorCode::InvalidBps);
+        
+        let registry = &mut ctx.accounts.registry;
+        registry.duplicate_slash_bps = duplicate_slash_bps;
+        registry.reporter_bounty_bps = reporter_bounty_bps;
+        
+        msg!("Duplicate slashing set: slash={}bps, bounty={}bps", 
+            duplicate_slash_bps, reporter_bounty_bps);
+        Ok(())
+    }
 
-#[account]
-pub struct Publisher {
-    pub owner: Pubkey,
-    pub name: String,               // Max 64 chars
-    pub metadata_uri: String,       // Max 200 chars (IPFS/Arweave link)
-    pub stake: u64,                 // USDC staked
-    pub reputation_score: u16,      // 0-1000 (scaled by 10, so 500 = 50.0)
-    pub alerts_submitted: u64,
-    pub alerts_accepted: u64,
-    pub total_earnings: u64,
-    pub registered_at: i64,
-    pub active: bool,
-    pub slashed: bool,
-    pub bump: u8,
-}
+    /// Slash a publisher for re-publishing another publisher's content
+    /// (CPI from alert_registry only, after it has verified the duplicate)
+    pub fn slash_duplicate(ctx: Context<SlashDuplicate>, duplicate_alert: Pubkey) -> Result<()> {
+        let registry = &ctx.accounts.registry;
+        require!(registry.duplicate_slash_bps > 0, ErrorCode::DuplicateSlashingDisabled);
+        
+        let publisher = &mut ctx.accounts.publisher;
+        let slash_amount = (publisher.stake as u128)
+            .checked_mul(registry.duplicate_slash_bps as u128)
+            .ok_or(ErrorCode::Overflow)?
+            .checked_div(10000)
+            .ok_or(ErrorCode::Overflow)? as u64;
+        require!(slash_amount > 0, ErrorCode::InsufficientStake);
+        
+        let bounty = (slash_amount as u128)
+            .checked_mul(registry.reporter_bounty_bps as u128)
+            .ok_or(ErrorCode::Overflow)?
+            .checked_div(10000)
+            .ok_or(ErrorCode::Overflow)? as u64;
+        
+        let reporter_token_account = ctx.accounts.reporter_token_account.to_account_info();
+        slash_stake(
+            publisher,
+            &ctx.accounts.stake_vault.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.bumps.stake_vault,
+            slash_amount,
+            Some((&reporter_token_account, bounty)),
+        )?;
+        publisher.alerts_first_reported = publisher.alerts_first_reported.saturating_sub(1);
+        publisher.recompute_reputation(&registry.reputation, Clock::get()?.unix_timestamp);
+        
+        emit!(DuplicateSlashed {
+            publisher: publisher.key(),
+            duplicate_alert,
+            amount: slash_amount,
+            reporter_bounty: bounty,
+        });
+        
+        msg!("Publisher {} slashed {} for duplicate alert {}", 
+            publisher.name, slash_amount, duplicate_alert);
+        Ok(())
+    }
 
-// === Contexts ===
+    /// Propose slashing a publisher (authority only). The publisher can
+    /// appeal until the deadline; evidence alerts are passed as
+    /// `remaining_accounts` and must be alert registry `Alert` accounts
+    /// published by this publisher.
+    pub fn propose_slash(
+        ctx: Context<ProposeSlash>,
+        slash_amount: u64,
+        reason: String,
+    ) -> Result<()> {
+        require!(reason.len() <= 128, ErrorCode::ReasonTooLong);
+        require!(slash_amount > 0, ErrorCode::InvalidAmount);
+        require!(slash_amount <= ctx.accounts.publisher.stake, ErrorCode::InsufficientStake);
+        require!(
+            !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() <= MAX_EVIDENCE_ALERTS,
+            ErrorCode::InvalidEvidence
+        );
+        
+        let mut evidence = Vec::with_capacity(ctx.remaining_accounts.len());
+        for alert in ctx.remaining_accounts {
+            require_keys_eq!(
+                evidence_publisher(alert)?,
+                ctx.accounts.publisher.identity,
+                ErrorCode::InvalidEvidence
+            );
+            evidence.push(alert.key());
+        }
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
+        let now = Clock::get()?.unix_timestamp;
+        let publisher = &mut ctx.accounts.publisher;
+        let proposal = &mut ctx.accounts.proposal;
+        open_slash_proposal(
+            proposal,
+            publisher,
+            slash_amount,
+            reason,
+            evidence,
+            now,
+            ctx.accounts.registry.challenge_period,
+            ctx.bumps.proposal,
+        )?;
+        
+        msg!("Slash proposed for {}: {} ({})", publisher.name, slash_amount, proposal.reason);
+        Ok(())
+    }
+
+    /// Appeal a pending slash before its deadline (publisher owner only)
+    pub fn appeal_slash(ctx: Context<AppealSlash>, appeal_hash: [u8; 32]) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        
+        require!(proposal.status == SlashStatus::Pending, ErrorCode::InvalidProposalStatus);
+        require!(Clock::get()?.unix_timestamp <= proposal.deadline, ErrorCode::ChallengeWindowClosed);
+        
+        proposal.appeal_hash = appeal_hash;
+        proposal.status = SlashStatus::Appealed;
+        
+        emit!(SlashAppealed {
+            proposal: proposal.key(),
+            appeal_hash,
+        });
+        
+        msg!("Slash proposal {} appealed", proposal.index);
+        Ok(())
+    }
+
+    /// Lift a spam suspension once its slash proposals are resolved (authority only)
+    pub fn reinstate_publisher(ctx: Context<ReinstatePublisher>) -> Result<()> {
+        let registry = &ctx.accounts.registry;
+        let publisher = &mut ctx.accounts.publisher;
+        require!(publisher.suspended, ErrorCode::NotSuspended);
+        require!(publisher.open_slash_proposals == 0, ErrorCode::SlashPending);
+        
+        publisher.suspended = false;
+        publisher.active = !publisher.slashed
+            && publisher.stake.saturating_sub(publisher.pending_unstake) >= registry.min_stake;
+        
+        msg!("Publisher {} reinstated: active={}", publisher.name, publisher.active);
+        Ok(())
+    }
+
+    /// Rule on an appeal (authority only). Upheld appeals dismiss the slash.
+    pub fn resolve_appeal(ctx: Context<ResolveAppeal>, uphold: bool) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        require!(proposal.status == SlashStatus::Appealed, ErrorCode::InvalidProposalStatus);
+        
+        if uphold {
+            proposal.status = SlashStatus::Dismissed;
+            proposal.resolved_at = Clock::get()?.unix_timestamp;
+            let publisher = &mut ctx.accounts.publisher;
+            publisher.open_slash_proposals = publisher.open_slash_proposals.saturating_sub(1);
+        } else {
+            proposal.status = SlashStatus::AppealRejected;
+        }
+        
+        emit!(AppealResolved {
+            proposal: proposal.key(),
+            upheld: uphold,
+        });
+        
+        msg!("Appeal on slash proposal {} {}", proposal.index, 
+            if uphold { "upheld" } else { "rejected" });
+        Ok(())
+    }
+
+    /// Execute a slash once its window has passed unappealed or its appeal
+    /// was rejected (authority only). Stake moves to the treasury.
+    pub fn execute_slash(ctx: Context<ExecuteSlash>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let now = Clock::get()?.unix_timestamp;
+        
+        match proposal.status {
+            SlashStatus::Pending => require!(now > proposal.deadline, ErrorCode::ChallengeWindowOpen),
+            SlashStatus::AppealRejected => {}
+            _ => return err!(ErrorCode::InvalidProposalStatus),
+        }
+        
+        let publisher = &mut ctx.accounts.publisher;
+        let slash_amount = proposal.slash_amount.min(publisher.stake);
+        slash_stake(
+            publisher,
+            &ctx.accounts.stake_vault.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.bumps.stake_vault,
+            slash_amount,
+            None,
+        )?;
+        publisher.recompute_reputation(&ctx.accounts.registry.reputation, Clock::get()?.unix_timestamp);
+        publisher.open_slash_proposals = publisher.open_slash_proposals.saturating_sub(1);
+        
+        proposal.status = SlashStatus::Executed;
+        proposal.resolved_at = now;
+        
+        emit!(SlashExecuted {
+            proposal: proposal.key(),
+            publisher: publisher.key(),
+            amount: slash_amount,
+        });
+        
+        msg!("Publisher {} slashed {} for: {}", publisher.name, slash_amount, proposal.reason);
+        Ok(())
+    }
+
+    /// Add stake to an existing publisher bond
+    pub fn add_stake(ctx: Context<AddStake>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        
+        let registry = &ctx.accounts.registry;
+        let publisher = &mut ctx.accounts.publisher;
+        require!(!publisher.slashed, ErrorCode::PublisherSlashed);
+        
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.publisher_token_account.to_account_info(),
+            to: ctx.accounts.stake_vault.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        transfer(cpi_ctx, amount)?;
+        
+        publisher.stake = publisher.stake.checked_add(amount)
+            .ok_or(ErrorCode::Overflow)?;
+        // Suspended publishers stay inactive until the authority reinstates them
+        if !publisher.suspended
+            && publisher.stake.saturating_sub(publisher.pending_unstake) >= registry.min_stake
+        {
+            publisher.active = true;
+        }
+        
+        msg!("Publisher {} added stake: {} (total={})", publisher.name, amount, publisher.stake);
+        Ok(())
+    }
+
+    /// Start unbonding part or all of the stake. Stake stays slashable
+    /// until `complete_unstake` after the registry's unbonding period.
+    pub fn request_unstake(ctx: Context<RequestUnstake>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        
+        let registry = &ctx.accounts.registry;
+        let publisher = &mut ctx.accounts.publisher;
+        require!(!publisher.slashed, ErrorCode::PublisherSlashed);
+        
+        let pending = publisher.pending_unstake.checked_add(amount)
+            .ok_or(ErrorCode::Overflow)?;
+        require!(pending <= publisher.stake, ErrorCode::InsufficientStake);
+        
+        // Partial unstakes must leave at least the minimum bond
+        let remaining = publisher.stake - pending;
+        require!(remaining == 0 || remaining >= registry.min_stake, ErrorCode::InsufficientStake);
+        
+        let now = Clock::get()?.unix_timestamp;
+        publisher.pending_unstake = pending;
+        publisher.unstake_available_at = now.checked_add(registry.unbonding_period)
+            .ok_or(ErrorCode::Overflow)?;
+        
+        msg!("Publisher {} requested unstake: {} (available at {})", 
+            publisher.name, pending, publisher.unstake_available_at);
+        Ok(())
+    }
+
+    /// Withdraw unbonded stake once the cooldown has elapsed
+    pub fn complete_unstake(ctx: Context<CompleteUnstake>) -> Result<()> {
+        let registry = &ctx.accounts.registry;
+        let publisher = &mut ctx.accounts.publisher;
+        
+        require!(!publisher.slashed, ErrorCode::PublisherSlashed);
+        require!(publisher.pending_unstake > 0, ErrorCode::NoPendingUnstake);
+        require!(publisher.open_slash_proposals == 0, ErrorCode::SlashPending);
+        require!(
+            Clock::get()?.unix_timestamp >= publisher.unstake_available_at,
+            ErrorCode::UnbondingInProgress
+        );
+        
+        let amount = publisher.pending_unstake.min(publisher.stake);
+        publisher.stake = publisher.stake.checked_sub(amount)
+            .ok_or(ErrorCode::Overflow)?;
+        publisher.pending_unstake = 0;
+        publisher.unstake_available_at = 0;
+        if publisher.stake < registry.min_stake {
+            publisher.active = false;
+        }
+        
+        // Transfer stake back to publisher
+        let owner_key = ctx.accounts.owner.key();
+        let seeds = &[
+            b"stake_vault",
+            owner_key.as_ref(),
+            &[ctx.bumps.stake_vault],
+        ];
+        let signer = &[&seeds[..]];
+        
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.stake_vault.to_account_info(),
+            to: ctx.accounts.publisher_token_account.to_account_info(),
+            authority: ctx.accounts.stake_vault.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        transfer(cpi_ctx, amount)?;
+        
+        msg!("Publisher {} withdrew stake: {} (remaining={})", publisher.name, amount, publisher.stake);
+        Ok(())
+    }
+}
+
+// === Account Structures ===
+
+#[account]
+pub struct PublisherRegistry {
+    pub authority: Pubkey,
+    pub usdc_mint: Pubkey,
+    pub min_stake: u64,             // Minimum USDC stake required
+    pub publisher_share_bps: u16,   // Publisher revenue share (5000 = 50%)
+    pub total_publishers: u64,
+    pub total_payouts: u64,
+    pub bump: u8,
+    pub unbonding_period: i64,      // Seconds between request_unstake and complete_unstake
+    pub challenge_period: i64,      // Seconds a publisher has to appeal a slash
+    pub treasury: Pubkey,           // Receives slashed stake, forfeits and pool sweeps
+    pub duplicate_slash_bps: u16,   // Fraction of stake slashed per duplicate (0 = disabled)
+    pub reporter_bounty_bps: u16,   // Fraction of the slash paid to the reporter
+    pub spam_rejection_limit: u8,   // Rejections tolerated within spam_window
+    pub spam_window: i64,           // Rolling window in seconds
+    pub spam_slash_bps: u16,        // Fraction of stake proposed for slashing on spam
+    pub reputation: ReputationParams,
+    pub total_unclaimed: u64,       // Accrued publisher earnings still held in the revenue pool
+}
+
+/// Reputation model, all scores scaled by 10 (500 = 50.0)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ReputationParams {
+    pub gain: u16,                  // Accuracy added per accepted alert
+    pub loss: u16,                  // Accuracy removed per rejected alert
+    pub half_life: i64,             // Seconds for idle accuracy over `initial` to halve (0 = no decay)
+    pub floor: u16,                 // Lowest accuracy reachable through rejections
+    pub cap: u16,                   // Highest accuracy reachable through acceptances
+    pub initial: u16,               // Starting score, decay baseline and neutral sub-score
+    pub accuracy_weight: u16,       // Weights in bps, summing to 10000
+    pub engagement_weight: u16,
+    pub timeliness_weight: u16,
+    pub rating_weight: u16,
+    pub target_deliveries: u32,     // Deliveries per registered alert for full engagement
+}
+
+#[account]
+pub struct Publisher {
+    pub owner: Pubkey,
+    pub identity: Pubkey,           // Original owner key, stable across ownership transfers
+    pub pending_owner: Pubkey,      // Set by transfer_publisher_ownership (default = none)
+    pub name: String,               // Max 64 chars
+    pub metadata_uri: String,       // Max 200 chars (IPFS/Arweave link)
+    pub stake: u64,                 // USDC staked (including pending unstake)
+    pub pending_unstake: u64,       // Unbonding, still slashable
+    pub unstake_available_at: i64,
+    pub slash_proposal_count: u32,
+    pub open_slash_proposals: u32,  // Blocks complete_unstake while non-zero
+    pub reputation_score: u16,      // 0-1000 (scaled by 10, so 500 = 50.0), weighted from the sub-scores
+    pub reputation_updated_at: i64, // Last accept/reject; idle decay is measured from here
+    pub accuracy_score: u16,        // Accept/reject history, decays while idle
+    pub engagement_score: u16,      // Deliveries per registered alert
+    pub timeliness_score: u16,      // Share of registered alerts reported first
+    pub rating_score: u16,          // Share of paid-weighted subscriber upvotes
+    pub alerts_registered: u64,     // Alerts registered on-chain via alert_registry
+    pub alerts_first_reported: u64, // Registered alerts not found to be duplicates
+    pub rating_up_weight: u64,      // Sum of amounts paid by upvoting subscribers
+    pub rating_down_weight: u64,
+    pub alerts_submitted: u64,
+    pub alerts_accepted: u64,
+    pub recent_rejections: [i64; MAX_TRACKED_REJECTIONS], // Ring buffer of rejection timestamps
+    pub rejection_cursor: u8,
+    pub total_earnings: u64,        // Lifetime USDC paid out
+    pub unclaimed_earnings: u64,    // Accrued from delivery receipts, not yet paid
+    pub alerts_delivered: u64,
+    pub registered_at: i64,
+    pub active: bool,
+    pub slashed: bool,
+    pub suspended: bool,            // Set by spam detection; cleared only by reinstate_publisher
+    pub bump: u8,
+}
+
+impl Publisher {
+    /// Accuracy sub-score at `now` after idle decay. Scores at or below the
+    /// baseline are left alone so penalties can't be waited out.
+    pub fn current_accuracy(&self, params: &ReputationParams, now: i64) -> u16 {
+        idle_decay(self.accuracy_score, params, now.saturating_sub(self.reputation_updated_at))
+    }
+    
+    /// Weighted reputation at `now`. The combined score decays toward the
+    /// baseline like accuracy does, so idle engagement, timeliness and
+    /// rating sub-scores don't keep a stale score high either.
+    pub fn current_reputation(&self, params: &ReputationParams, now: i64) -> u16 {
+        idle_decay(
+            self.weighted_reputation(params, self.accuracy_score),
+            params,
+            now.saturating_sub(self.reputation_updated_at),
+        )
+    }
+    
+    /// Persist idle decay on publisher activity and restart the idle clock.
+    /// Only accept/reject activity may call this; refreshes must not.
+    pub fn decay_reputation(&mut self, params: &ReputationParams, now: i64) {
+        self.accuracy_score = self.current_accuracy(params, now);
+        self.reputation_updated_at = now;
+        self.recompute_reputation(params, now);
+    }
+    
+    /// Rebuild the counter-driven sub-scores and store the weighted
+    /// reputation at `now`, leaving the idle clock alone.
+    pub fn recompute_reputation(&mut self, params: &ReputationParams, now: i64) {
+        self.engagement_score = ratio_score(
+            self.alerts_delivered as u128,
+            self.alerts_registered as u128 * params.target_deliveries as u128,
+            params.initial,
+        );
+        self.timeliness_score = ratio_score(
+            self.alerts_first_reported as u128,
+            self.alerts_registered as u128,
+            params.initial,
+        );
+        self.rating_score = ratio_score(
+            self.rating_up_weight as u128,
+            self.rating_up_weight as u128 + self.rating_down_weight as u128,
+            params.initial,
+        );
+        self.reputation_score = self.current_reputation(params, now);
+    }
+    
+    fn weighted_reputation(&self, params: &ReputationParams, accuracy: u16) -> u16 {
+        let weighted = accuracy as u32 * params.accuracy_weight as u32
+            + self.engagement_score as u32 * params.engagement_weight as u32
+            + self.timeliness_score as u32 * params.timeliness_weight as u32
+            + self.rating_score as u32 * params.rating_weight as u32;
+        (weighted / 10000) as u16
+    }
+}
+
+/// Decay `score` after `elapsed` idle seconds: the excess over the baseline
+/// halves every `half_life` (linear within a period); scores at or below
+/// the baseline are returned unchanged.
+fn idle_decay(score: u16, params: &ReputationParams, elapsed: i64) -> u16 {
+    if params.half_life <= 0 || elapsed <= 0 || score <= params.initial {
+        return score;
+    }
+    
+    let halvings = elapsed / params.half_life;
+    if halvings >= 16 {
+        return params.initial;
+    }
+    let mut excess = ((score - params.initial) >> halvings) as u128;
+    let remainder = (elapsed % params.half_life) as u128;
+    excess -= excess * remainder / (2 * params.half_life as u128);
+    
+    params.initial + excess as u16
+}
+
+/// Scale `part / whole` to 0-1000, or `neutral` when there is no data yet.
+fn ratio_score(part: u128, whole: u128, neutral: u16) -> u16 {
+    if whole == 0 {
+        return neutral;
+    }
+    (part * 1000 / whole).min(1000) as u16
+}
+
+#[account]
+pub struct PublisherDelegate {
+    pub publisher: Pubkey,          // Publisher account this key signs for
+    pub delegate: Pubkey,
+    pub channels: u32,              // Catalog channel bitmap allowed (0 = any channel)
+    pub expires_at: i64,            // 0 = never expires
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl PublisherDelegate {
+    /// Whether the delegate may register an alert on `channel_index` at `now`.
+    pub fn allows(&self, channel_index: u8, now: i64) -> bool {
+        let unexpired = self.expires_at == 0 || now < self.expires_at;
+        let channel_ok = self.channels == 0 || self.channels & (1u32 << channel_index) != 0;
+        unexpired && channel_ok
+    }
+}
+
+#[account]
+pub struct SlashProposal {
+    pub publisher: Pubkey,
+    pub index: u32,
+    pub slash_amount: u64,
+    pub reason: String,             // Max 128 chars
+    pub evidence_hash: [u8; 32],    // SHA256 over the evidence alert account keys
+    pub evidence: Vec<Pubkey>,      // alert_registry::Alert accounts (max 4)
+    pub proposed_at: i64,
+    pub deadline: i64,              // Appeals accepted until this time
+    pub appeal_hash: [u8; 32],      // Hash of the publisher's off-chain appeal
+    pub status: SlashStatus,
+    pub resolved_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SlashStatus {
+    Pending,
+    Appealed,
+    AppealRejected,
+    Dismissed,
+    Executed,
+}
+
+// === Events ===
+
+#[event]
+pub struct SlashProposed {
+    pub proposal: Pubkey,
+    pub publisher: Pubkey,
+    pub slash_amount: u64,
+    pub evidence_hash: [u8; 32],
+    pub deadline: i64,
+}
+
+#[event]
+pub struct SlashAppealed {
+    pub proposal: Pubkey,
+    pub appeal_hash: [u8; 32],
+}
+
+#[event]
+pub struct AppealResolved {
+    pub proposal: Pubkey,
+    pub upheld: bool,
+}
+
+#[event]
+pub struct SlashExecuted {
+    pub proposal: Pubkey,
+    pub publisher: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DuplicateSlashed {
+    pub publisher: Pubkey,
+    pub duplicate_alert: Pubkey,
+    pub amount: u64,
+    pub reporter_bounty: u64,
+}
+
+#[event]
+pub struct EarningsClaimed {
+    pub publisher: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub total_earnings: u64,
+}
+
+#[event]
+pub struct EarningsForfeited {
+    pub publisher: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PublisherOwnershipTransferred {
+    pub publisher: Pubkey,
+    pub identity: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+// === Contexts ===
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32 + 32 + 8 + 2 + 8 + 8 + 1 + 8 + 8 + 32 + 2 + 2 + 1 + 8 + 2 + 2 + 2 + 8 + 2 + 2 + 2 + 2 + 2 + 2 + 2 + 4 + 8,
+        seeds = [b"publisher_registry"],
+        bump
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
+    
+    /// Revenue pool funded by subscription charges
+    #[account(
+        init,
+        payer = authority,
+        token::mint = usdc_mint,
+        token::authority = revenue_pool,
+        seeds = [b"revenue_pool", registry.key().as_ref()],
+        bump
+    )]
+    pub revenue_pool: Account<'info, TokenAccount>,
+    
+    pub usdc_mint: Account<'info, Mint>,
+    
+    /// Receives slashed stake, forfeited earnings and revenue pool sweeps
+    #[account(
+        constraint = treasury.mint == usdc_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterPublisher<'info> {
+    #[account(
+        mut,
+        seeds = [b"publisher_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = PUBLISHER_SPACE,
+        seeds = [b"publisher", owner.key().as_ref()],
+        bump
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
+    #[account(
+        init,
+        payer = owner,
+        token::mint = usdc_mint,
+        token::authority = stake_vault,
+        seeds = [b"stake_vault", owner.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub publisher_token_account: Account<'info, TokenAccount>,
+    
+    #[account(address = registry.usdc_mint @ ErrorCode::InvalidMint)]
+    pub usdc_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePublisher<'info> {
+    #[account(
+        mut,
+        seeds = [b"publisher", owner.key().as_ref()],
+        bump = publisher.bump,
+        has_one = owner
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct AddDelegate<'info> {
+    #[account(
+        seeds = [b"publisher", owner.key().as_ref()],
+        bump = publisher.bump,
+        has_one = owner
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + 32 + 32 + 4 + 8 + 8 + 1,
+        seeds = [b"delegate", publisher.key().as_ref(), delegate.as_ref()],
+        bump
+    )]
+    pub delegate_account: Account<'info, PublisherDelegate>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeDelegate<'info> {
+    #[account(
+        seeds = [b"publisher", owner.key().as_ref()],
+        bump = publisher.bump,
+        has_one = owner
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"delegate", publisher.key().as_ref(), delegate_account.delegate.as_ref()],
+        bump = delegate_account.bump
+    )]
+    pub delegate_account: Account<'info, PublisherDelegate>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptPublisherOwnership<'info> {
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [b"publisher", previous_publisher.owner.as_ref()],
+        bump = previous_publisher.bump,
+        constraint = previous_publisher.pending_owner == new_owner.key() @ ErrorCode::NoPendingOwner
+    )]
+    pub previous_publisher: Account<'info, Publisher>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake_vault", previous_publisher.owner.as_ref()],
+        bump
+    )]
+    pub previous_stake_vault: Account<'info, TokenAccount>,
+    
+    /// Receives the rent of the closed stake vault
+    #[account(mut, address = previous_publisher.owner)]
+    pub previous_owner: SystemAccount<'info>,
+    
+    #[account(
+        init,
+        payer = new_owner,
+        space = PUBLISHER_SPACE,
+        seeds = [b"publisher", new_owner.key().as_ref()],
+        bump
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
+    #[account(
+        init,
+        payer = new_owner,
+        token::mint = usdc_mint,
+        token::authority = stake_vault,
+        seeds = [b"stake_vault", new_owner.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = registry.usdc_mint @ ErrorCode::InvalidMint)]
+    pub usdc_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RecordRejection<'info> {
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = registry.bump,
+        has_one = authority
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [b"publisher", publisher.owner.as_ref()],
+        bump = publisher.bump
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
+    /// Slash proposal opened when this rejection crosses the spam limit
+    #[account(
+        init,
+        payer = authority,
+        space = SLASH_PROPOSAL_SPACE,
+        seeds = [
+            b"slash_proposal",
+            publisher.key().as_ref(),
+            &publisher.slash_proposal_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub proposal: Option<Account<'info, SlashProposal>>,
+    
+    /// Authority (protocol-controlled)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AlertRegistryUpdate<'info> {
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [b"publisher", publisher.owner.as_ref()],
+        bump = publisher.bump
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
+    /// Alert registry PDA, signed via CPI by the alert registry program
+    #[account(
+        seeds = [b"registry"],
+        bump,
+        seeds::program = ALERT_REGISTRY_PROGRAM_ID
+    )]
+    pub alert_registry: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RefreshReputation<'info> {
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [b"publisher", publisher.owner.as_ref()],
+        bump = publisher.bump
+    )]
+    pub publisher: Account<'info, Publisher>,
+}
+
+#[derive(Accounts)]
+pub struct AlertDeliveryUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [b"publisher_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [b"publisher", publisher.owner.as_ref()],
+        bump = publisher.bump
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
+    /// Alert registry PDA, signed via CPI by the alert registry program
+    #[account(
+        seeds = [b"registry"],
+        bump,
+        seeds::program = ALERT_REGISTRY_PROGRAM_ID
+    )]
+    pub alert_registry: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DistributeRevenue<'info> {
+    #[account(
+        mut,
+        seeds = [b"publisher_registry"],
+        bump = registry.bump,
+        has_one = authority
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [b"publisher", publisher.owner.as_ref()],
+        bump = publisher.bump
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
+    #[account(
+        mut,
+        seeds = [b"revenue_pool", registry.key().as_ref()],
+        bump
+    )]
+    pub revenue_pool: Account<'info, TokenAccount>,
+    
+    /// Publisher owner's USDC associated token account
+    #[account(
+        mut,
+        associated_token::mint = registry.usdc_mint,
+        associated_token::authority = publisher.owner
+    )]
+    pub publisher_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    /// Authority (protocol-controlled)
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ForfeitEarnings<'info> {
+    #[account(
+        mut,
+        seeds = [b"publisher_registry"],
+        bump = registry.bump,
+        has_one = authority
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [b"publisher", publisher.owner.as_ref()],
+        bump = publisher.bump
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
+    #[account(
+        mut,
+        seeds = [b"revenue_pool", registry.key().as_ref()],
+        bump
+    )]
+    pub revenue_pool: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        address = registry.treasury @ ErrorCode::InvalidTreasury,
+        constraint = treasury.mint == registry.usdc_mint @ ErrorCode::InvalidMint
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SweepRevenuePool<'info> {
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = registry.bump,
+        has_one = authority
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [b"revenue_pool", registry.key().as_ref()],
+        bump
+    )]
+    pub revenue_pool: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        address = registry.treasury @ ErrorCode::InvalidTreasury,
+        constraint = treasury.mint == registry.usdc_mint @ ErrorCode::InvalidMint
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimEarnings<'info> {
+    #[account(
+        mut,
+        seeds = [b"publisher_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
+    
     #[account(
-        init,
-        payer = authority,
-        space = 8 + 32 + 32 + 8 + 2 + 8 + 8 + 1,
-        seeds = [b"publisher_registry"],
+        mut,
+        seeds = [b"publisher", owner.key().as_ref()],
+        bump = publisher.bump,
+        has_one = owner
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
+    #[account(
+        mut,
+        seeds = [b"revenue_pool", registry.key().as_ref()],
         bump
     )]
-    pub registry: Account<'info, PublisherRegistry>,
+    pub revenue_pool: Account<'info, TokenAccount>,
     
-    /// CHECK: USDC mint address
-    pub usdc_mint: AccountInfo<'info>,
+    /// Owner's USDC associated token account
+    #[account(
+        mut,
+        associated_token::mint = registry.usdc_mint,
+        associated_token::authority = owner
+    )]
+    pub publisher_token_account: Account<'info, TokenAccount>,
     
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    pub owner: Signer<'info>,
     
-    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
-pub struct RegisterPublisher<'info> {
+pub struct SetTreasury<'info> {
     #[account(
         mut,
+        seeds = [b"publisher_registry"],
+        bump = registry.bump,
+        has_one = authority
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
+    
+    #[account(
+        constraint = treasury.mint == registry.usdc_mint @ ErrorCode::InvalidMint
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SlashDuplicate<'info> {
+    #[account(
         seeds = [b"publisher_registry"],
         bump = registry.bump
     )]
     pub registry: Account<'info, PublisherRegistry>,
     
     #[account(
-        init,
-        payer = owner,
-        space = 8 + 32 + 4 + 64 + 4 + 200 + 8 + 2 + 8 + 8 + 8 + 8 + 1 + 1 + 1,
-        seeds = [b"publisher", owner.key().as_ref()],
-        bump
+        mut,
+        seeds = [b"publisher", publisher.owner.as_ref()],
+        bump = publisher.bump
     )]
     pub publisher: Account<'info, Publisher>,
     
     #[account(
-        init,
-        payer = owner,
-        token::mint = usdc_mint,
-        token::authority = stake_vault,
-        seeds = [b"stake_vault", owner.key().as_ref()],
+        mut,
+        seeds = [b"stake_vault", publisher.owner.as_ref()],
         bump
     )]
     pub stake_vault: Account<'info, TokenAccount>,
     
-    #[account(mut)]
-    pub publisher_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = registry.treasury @ ErrorCode::InvalidTreasury
+    )]
+    pub treasury: Account<'info, TokenAccount>,
     
-    /// CHECK: USDC mint
-    pub usdc_mint: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = reporter_token_account.mint == registry.usdc_mint @ ErrorCode::InvalidMint
+    )]
+    pub reporter_token_account: Account<'info, TokenAccount>,
     
-    #[account(mut)]
-    pub owner: Signer<'info>,
+    /// Alert registry PDA, signed via CPI by the alert registry program
+    #[account(
+        seeds = [b"registry"],
+        bump,
+        seeds::program = ALERT_REGISTRY_PROGRAM_ID
+    )]
+    pub alert_registry: Signer<'info>,
     
     pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
 #[derive(Accounts)]
-pub struct RecordSubmission<'info> {
+pub struct ProposeSlash<'info> {
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = registry.bump,
+        has_one = authority
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [b"publisher", publisher.owner.as_ref()],
+        bump = publisher.bump
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = SLASH_PROPOSAL_SPACE,
+        seeds = [
+            b"slash_proposal",
+            publisher.key().as_ref(),
+            &publisher.slash_proposal_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, SlashProposal>,
+    
     #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AppealSlash<'info> {
+    #[account(
+        seeds = [b"publisher", owner.key().as_ref()],
+        bump = publisher.bump,
+        has_one = owner
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
+    #[account(
+        mut,
+        seeds = [b"slash_proposal", publisher.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, SlashProposal>,
+    
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReinstatePublisher<'info> {
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = registry.bump,
+        has_one = authority
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [b"publisher", publisher.owner.as_ref()],
+        bump = publisher.bump
+    )]
     pub publisher: Account<'info, Publisher>,
     
-    /// Authority (protocol-controlled)
     pub authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct DistributeRevenue<'info> {
+pub struct ResolveAppeal<'info> {
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = registry.bump,
+        has_one = authority
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [b"publisher", publisher.owner.as_ref()],
+        bump = publisher.bump
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
     #[account(
         mut,
+        seeds = [b"slash_proposal", publisher.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, SlashProposal>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteSlash<'info> {
+    #[account(
         seeds = [b"publisher_registry"],
-        bump = registry.bump
+        bump = registry.bump,
+        has_one = authority
     )]
     pub registry: Account<'info, PublisherRegistry>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"publisher", publisher.owner.as_ref()],
+        bump = publisher.bump
+    )]
     pub publisher: Account<'info, Publisher>,
     
     #[account(
         mut,
-        seeds = [b"revenue_pool", registry.key().as_ref()],
+        seeds = [b"slash_proposal", publisher.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, SlashProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake_vault", publisher.owner.as_ref()],
         bump
     )]
-    pub revenue_pool: Account<'info, TokenAccount>,
+    pub stake_vault: Account<'info, TokenAccount>,
     
-    #[account(mut)]
-    pub publisher_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = registry.treasury @ ErrorCode::InvalidTreasury,
+        constraint = treasury.mint == registry.usdc_mint @ ErrorCode::InvalidMint
+    )]
+    pub treasury: Account<'info, TokenAccount>,
     
     pub token_program: Program<'info, Token>,
     
-    /// Authority (protocol-controlled)
     pub authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct SlashPublisher<'info> {
+pub struct UpdateRegistry<'info> {
     #[account(
+        mut,
         seeds = [b"publisher_registry"],
         bump = registry.bump,
         has_one = authority
     )]
     pub registry: Account<'info, PublisherRegistry>,
     
-    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AddStake<'info> {
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [b"publisher", owner.key().as_ref()],
+        bump = publisher.bump,
+        has_one = owner
+    )]
     pub publisher: Account<'info, Publisher>,
     
     #[account(
         mut,
-        seeds = [b"stake_vault", publisher.owner.as_ref()],
+        seeds = [b"stake_vault", owner.key().as_ref()],
         bump
     )]
     pub stake_vault: Account<'info, TokenAccount>,
     
     #[account(mut)]
-    pub treasury: Account<'info, TokenAccount>,
+    pub publisher_token_account: Account<'info, TokenAccount>,
+    
+    pub owner: Signer<'info>,
     
     pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
     
-    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"publisher", owner.key().as_ref()],
+        bump = publisher.bump,
+        has_one = owner
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
+    pub owner: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct WithdrawStake<'info> {
+pub struct CompleteUnstake<'info> {
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PublisherRegistry>,
+    
     #[account(
         mut,
         seeds = [b"publisher", owner.key().as_ref()],
@@ -405,6 +1982,152 @@ pub struct WithdrawStake<'info> {
     pub token_program: Program<'info, Token>,
 }
 
+// === Helpers ===
+
+/// Move `amount` of a publisher's stake out of its vault and zero its
+/// accuracy sub-score (callers recompute the weighted reputation). An optional `(account, bounty)` is paid out of `amount` and
+/// the rest goes to the treasury.
+fn slash_stake<'info>(
+    publisher: &mut Publisher,
+    stake_vault: &AccountInfo<'info>,
+    treasury: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    stake_vault_bump: u8,
+    amount: u64,
+    bounty: Option<(&AccountInfo<'info>, u64)>,
+) -> Result<()> {
+    publisher.stake = publisher.stake.checked_sub(amount)
+        .ok_or(ErrorCode::Overflow)?;
+    publisher.pending_unstake = publisher.pending_unstake.min(publisher.stake);
+    publisher.accuracy_score = 0;
+    
+    if publisher.stake == 0 {
+        publisher.slashed = true;
+        publisher.active = false;
+    }
+    
+    let owner_key = publisher.owner;
+    let seeds = &[
+        b"stake_vault",
+        owner_key.as_ref(),
+        &[stake_vault_bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    let mut treasury_amount = amount;
+    let mut payouts = Vec::with_capacity(2);
+    if let Some((reporter, bounty_amount)) = bounty {
+        treasury_amount = amount.checked_sub(bounty_amount)
+            .ok_or(ErrorCode::Overflow)?;
+        payouts.push((reporter, bounty_amount));
+    }
+    payouts.push((treasury, treasury_amount));
+    
+    for (to, amount) in payouts {
+        if amount == 0 {
+            continue;
+        }
+        let cpi_accounts = Transfer {
+            from: stake_vault.clone(),
+            to: to.clone(),
+            authority: stake_vault.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(token_program.clone(), cpi_accounts, signer);
+        transfer(cpi_ctx, amount)?;
+    }
+    Ok(())
+}
+
+/// Populate a new slash proposal and count it against the publisher.
+#[allow(clippy::too_many_arguments)]
+fn open_slash_proposal(
+    proposal: &mut Account<SlashProposal>,
+    publisher: &mut Account<Publisher>,
+    slash_amount: u64,
+    reason: String,
+    evidence: Vec<Pubkey>,
+    now: i64,
+    challenge_period: i64,
+    bump: u8,
+) -> Result<()> {
+    let evidence_keys: Vec<&[u8]> = evidence.iter().map(|key| key.as_ref()).collect();
+    
+    proposal.publisher = publisher.key();
+    proposal.index = publisher.slash_proposal_count;
+    proposal.slash_amount = slash_amount;
+    proposal.reason = reason;
+    proposal.evidence_hash = if evidence.is_empty() { [0; 32] } else { hashv(&evidence_keys).to_bytes() };
+    proposal.evidence = evidence;
+    proposal.proposed_at = now;
+    proposal.deadline = now.checked_add(challenge_period)
+        .ok_or(ErrorCode::Overflow)?;
+    proposal.appeal_hash = [0; 32];
+    proposal.status = SlashStatus::Pending;
+    proposal.resolved_at = 0;
+    proposal.bump = bump;
+    
+    publisher.slash_proposal_count += 1;
+    publisher.open_slash_proposals += 1;
+    
+    emit!(SlashProposed {
+        proposal: proposal.key(),
+        publisher: publisher.key(),
+        slash_amount,
+        evidence_hash: proposal.evidence_hash,
+        deadline: proposal.deadline,
+    });
+    Ok(())
+}
+
+/// Read the publisher identity of an alert registry `Alert` account.
+///
+/// This crate can't depend on the alert registry (it depends on us), so the
+/// owner and discriminator are checked here and only the fields leading up
+/// to `publisher` are decoded.
+fn evidence_publisher(alert: &AccountInfo) -> Result<Pubkey> {
+    require_keys_eq!(*alert.owner, ALERT_REGISTRY_PROGRAM_ID, ErrorCode::InvalidEvidence);
+    let data = alert.try_borrow_data()?;
+    require!(
+        data.len() >= 8 && data[..8] == hash(b"account:Alert").to_bytes()[..8],
+        ErrorCode::InvalidEvidence
+    );
+    
+    // alert_id, channel, channel_index, content_hash, publisher
+    let (_, _, _, _, publisher) = <(String, String, u8, [u8; 32], Pubkey)>::deserialize(&mut &data[8..])
+        .map_err(|_| error!(ErrorCode::InvalidEvidence))?;
+    Ok(publisher)
+}
+
+/// Log a rejection and return how many fall inside the rolling window.
+fn record_rejection(publisher: &mut Publisher, now: i64, window: i64) -> u8 {
+    let cursor = publisher.rejection_cursor as usize % MAX_TRACKED_REJECTIONS;
+    publisher.recent_rejections[cursor] = now;
+    publisher.rejection_cursor = ((cursor + 1) % MAX_TRACKED_REJECTIONS) as u8;
+    
+    publisher.recent_rejections.iter()
+        .filter(|&&at| at != 0 && now.saturating_sub(at) < window)
+        .count() as u8
+}
+
+fn apply_submission(publisher: &mut Publisher, params: &ReputationParams, accepted: bool, now: i64) {
+    publisher.alerts_submitted += 1;
+    publisher.decay_reputation(params, now);
+    
+    if accepted {
+        publisher.alerts_accepted += 1;
+        // Increase accuracy for accepted alerts, never past the cap
+        publisher.accuracy_score = publisher.accuracy_score
+            .saturating_add(params.gain)
+            .min(params.cap.max(publisher.accuracy_score));
+    } else {
+        // Decrease accuracy for rejected alerts, never past the floor
+        publisher.accuracy_score = publisher.accuracy_score
+            .saturating_sub(params.loss)
+            .max(params.floor.min(publisher.accuracy_score));
+    }
+    publisher.recompute_reputation(params, now);
+}
+
 // === Errors ===
 
 #[error_code]
@@ -423,4 +2146,120 @@ pub enum ErrorCode {
     Overflow,
     #[msg("Unauthorized")]
     Unauthorized,
+    #[msg("Invalid amount")]
+    InvalidAmount,
+    #[msg("Amount exceeds unclaimed earnings")]
+    InsufficientEarnings,
+    #[msg("Invalid unbonding period")]
+    InvalidUnbondingPeriod,
+    #[msg("No pending unstake")]
+    NoPendingUnstake,
+    #[msg("Unbonding period has not elapsed")]
+    UnbondingInProgress,
+    #[msg("Invalid challenge period")]
+    InvalidChallengePeriod,
+    #[msg("Reason too long (max 128 chars)")]
+    ReasonTooLong,
+    #[msg("Evidence must be 1-4 alerts by this publisher")]
+    InvalidEvidence,
+    #[msg("Slash proposal is not in a valid state for this action")]
+    InvalidProposalStatus,
+    #[msg("Challenge window has closed")]
+    ChallengeWindowClosed,
+    #[msg("Challenge window is still open")]
+    ChallengeWindowOpen,
+    #[msg("Stake cannot be withdrawn while a slash is pending")]
+    SlashPending,
+    #[msg("Invalid basis points (max 10000)")]
+    InvalidBps,
+    #[msg("Duplicate-content slashing is disabled")]
+    DuplicateSlashingDisabled,
+    #[msg("Invalid USDC mint")]
+    InvalidMint,
+    #[msg("Invalid treasury account")]
+    InvalidTreasury,
+    #[msg("Invalid spam detection parameters")]
+    InvalidSpamParams,
+    #[msg("Slash proposal account required: rejection exceeds spam limit")]
+    SpamProposalRequired,
+    #[msg("Slash proposal supplied but spam limit not exceeded")]
+    UnexpectedSpamProposal,
+    #[msg("Invalid reputation parameters")]
+    InvalidReputationParams,
+    #[msg("New owner must differ from the current owner")]
+    InvalidNewOwner,
+    #[msg("Signer is not the pending owner")]
+    NoPendingOwner,
+    #[msg("Invalid delegate key or expiry")]
+    InvalidDelegate,
+    #[msg("Publisher is not suspended")]
+    NotSuspended,
+    #[msg("Amount exceeds the revenue pool surplus over accrued earnings")]
+    InsufficientSurplus,
+    #[msg("Publisher has not been slashed")]
+    NotSlashed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86_400;
+
+    fn idle_publisher(accuracy: u16) -> Publisher {
+        let mut publisher = Publisher::deserialize(&mut &[0u8; PUBLISHER_SPACE][..]).unwrap();
+        publisher.accuracy_score = accuracy;
+        publisher.recompute_reputation(&DEFAULT_REPUTATION, 0);
+        publisher
+    }
+
+    #[test]
+    fn accuracy_halves_its_excess_each_half_life() {
+        let params = DEFAULT_REPUTATION;
+        let publisher = idle_publisher(1000);
+        
+        assert_eq!(publisher.current_accuracy(&params, 0), 1000);
+        assert_eq!(publisher.current_accuracy(&params, 15 * DAY), 875);
+        assert_eq!(publisher.current_accuracy(&params, 30 * DAY), 750);
+        assert_eq!(publisher.current_accuracy(&params, 90 * DAY), 562);
+        assert_eq!(publisher.current_accuracy(&params, 720 * DAY), params.initial);
+    }
+
+    #[test]
+    fn accuracy_at_or_below_baseline_does_not_decay() {
+        let params = DEFAULT_REPUTATION;
+        assert_eq!(idle_publisher(params.initial).current_accuracy(&params, 90 * DAY), params.initial);
+        assert_eq!(idle_publisher(200).current_accuracy(&params, 90 * DAY), 200);
+    }
+
+    #[test]
+    fn idle_sub_scores_decay_with_the_combined_score() {
+        let params = DEFAULT_REPUTATION;
+        let mut publisher = idle_publisher(1000);
+        publisher.alerts_registered = 1;
+        publisher.alerts_first_reported = 1;
+        publisher.alerts_delivered = params.target_deliveries as u64;
+        publisher.rating_up_weight = 1;
+        publisher.recompute_reputation(&params, 0);
+        assert_eq!(publisher.reputation_score, 1000);
+        
+        publisher.recompute_reputation(&params, 90 * DAY);
+        assert_eq!(publisher.reputation_score, 562);
+        assert_eq!(publisher.current_reputation(&params, 720 * DAY), params.initial);
+    }
+
+    #[test]
+    fn frequent_refreshes_do_not_stall_decay() {
+        let params = DEFAULT_REPUTATION;
+        let mut refreshed = idle_publisher(1000);
+        let mut now = 0;
+        while now < 90 * DAY {
+            now += 2 * 3600;
+            refreshed.recompute_reputation(&params, now);
+        }
+        
+        let untouched = idle_publisher(1000);
+        assert_eq!(refreshed.current_accuracy(&params, now), 562);
+        assert_eq!(refreshed.reputation_score, untouched.current_reputation(&params, now));
+    }
 }
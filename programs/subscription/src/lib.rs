@@ -1,8 +1,17 @@
+// Anchor 0.31's `#[program]` expands to IDL account handlers that call the
+// deprecated `AccountInfo::realloc`; this crate doesn't call it itself.
+#![allow(deprecated)]
+
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::system_program;
 use anchor_spl::token::{Mint, Token, TokenAccount, Transfer, transfer};
+use publisher_registry::PublisherRegistry;
 
 declare_id!("H18zPB6sm7THZbBBtayAyjtQnfRvwN7E72Kxnomd2TVJ");
 
+const DELIVERY_RECEIPT_SPACE: usize = 8 + 32 + 32 + 8 + 8 + 1;
+
 /// Agent News Wire - Subscription Registry Program
 /// 
 /// Manages subscriber accounts, USDC deposits, channel subscriptions,
@@ -13,13 +22,19 @@ pub mod subscription_registry {
     use super::*;
 
     /// Initialize the protocol configuration
+    ///
+    /// The treasury fee and the publisher registry's publisher share are both
+    /// taken from the alert price, so together they can't exceed 100%.
     pub fn initialize(
         ctx: Context<Initialize>,
         price_per_alert: u64,
         treasury_fee_bps: u16,
     ) -> Result<()> {
+        check_fee_split(treasury_fee_bps, &ctx.accounts.publisher_registry)?;
+        
         let config = &mut ctx.accounts.config;
         config.authority = ctx.accounts.authority.key();
+        config.pending_authority = Pubkey::default();
         config.usdc_mint = ctx.accounts.usdc_mint.key();
         config.treasury = ctx.accounts.treasury.key();
         config.price_per_alert = price_per_alert;
@@ -34,16 +49,177 @@ pub mod subscription_registry {
         Ok(())
     }
 
+    /// Update pricing, fee split and treasury (authority only)
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        price_per_alert: Option<u64>,
+        treasury_fee_bps: Option<u16>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        
+        if let Some(price) = price_per_alert {
+            emit!(PriceUpdated {
+                old_price: config.price_per_alert,
+                new_price: price,
+            });
+            config.price_per_alert = price;
+        }
+        
+        if let Some(fee_bps) = treasury_fee_bps {
+            let publisher_registry = ctx.accounts.publisher_registry.as_ref()
+                .ok_or(ErrorCode::PublisherRegistryRequired)?;
+            check_fee_split(fee_bps, publisher_registry)?;
+            emit!(TreasuryFeeUpdated {
+                old_fee_bps: config.treasury_fee_bps,
+                new_fee_bps: fee_bps,
+            });
+            config.treasury_fee_bps = fee_bps;
+        }
+        
+        if let Some(treasury) = &ctx.accounts.treasury {
+            emit!(TreasuryUpdated {
+                old_treasury: config.treasury,
+                new_treasury: treasury.key(),
+            });
+            config.treasury = treasury.key();
+        }
+        
+        msg!("Config updated: price={}, treasury_fee={}bps, treasury={}", 
+            config.price_per_alert, config.treasury_fee_bps, config.treasury);
+        Ok(())
+    }
+
+    /// Propose a new protocol authority (authority only, default key cancels)
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.pending_authority = new_authority;
+        
+        emit!(AuthorityProposed {
+            authority: config.authority,
+            pending_authority: new_authority,
+        });
+        
+        msg!("Authority proposed: {}", new_authority);
+        Ok(())
+    }
+
+    /// Accept a pending authority transfer (signed by the proposed authority)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let previous = config.authority;
+        config.authority = config.pending_authority;
+        config.pending_authority = Pubkey::default();
+        
+        emit!(AuthorityTransferred {
+            old_authority: previous,
+            new_authority: config.authority,
+        });
+        
+        msg!("Authority transferred: {} -> {}", previous, config.authority);
+        Ok(())
+    }
+
+    /// Create the channel catalog (authority only)
+    pub fn initialize_channel_catalog(ctx: Context<InitializeChannelCatalog>) -> Result<()> {
+        let catalog = &mut ctx.accounts.channel_catalog;
+        catalog.channels = Vec::new();
+        catalog.active_mask = 0;
+        catalog.bump = ctx.bumps.channel_catalog;
+        
+        msg!("Channel catalog initialized");
+        Ok(())
+    }
+
+    /// Add a channel to the catalog, assigning it the next free bit index
+    pub fn add_channel(ctx: Context<ManageChannelCatalog>, name: String) -> Result<()> {
+        require!(!name.is_empty() && name.len() <= 32, ErrorCode::InvalidChannelName);
+        
+        let catalog = &mut ctx.accounts.channel_catalog;
+        require!(catalog.channels.len() < 32, ErrorCode::TooManyChannels);
+        require!(
+            !catalog.channels.iter().any(|entry| entry.name == name),
+            ErrorCode::ChannelExists
+        );
+        
+        let index = catalog.channels.len() as u8;
+        catalog.channels.push(ChannelEntry {
+            name: name.clone(),
+            active: true,
+        });
+        catalog.active_mask |= 1u32 << index;
+        
+        emit!(ChannelAdded {
+            index,
+            name: name.clone(),
+        });
+        
+        msg!("Channel added: {} -> bit {}", name, index);
+        Ok(())
+    }
+
+    /// Retire a channel. Its bit index is never reassigned.
+    pub fn retire_channel(ctx: Context<ManageChannelCatalog>, index: u8) -> Result<()> {
+        let catalog = &mut ctx.accounts.channel_catalog;
+        let entry = catalog.channels.get_mut(index as usize)
+            .ok_or(ErrorCode::InvalidChannel)?;
+        require!(entry.active, ErrorCode::InvalidChannel);
+        
+        entry.active = false;
+        catalog.active_mask &= !(1u32 << index);
+        
+        emit!(ChannelRetired { index });
+        
+        msg!("Channel retired: bit {}", index);
+        Ok(())
+    }
+
+    /// Create the per-channel price table (authority only)
+    pub fn initialize_channel_pricing(ctx: Context<InitializeChannelPricing>) -> Result<()> {
+        let pricing = &mut ctx.accounts.channel_pricing;
+        pricing.prices = [0; 32];
+        pricing.bump = ctx.bumps.channel_pricing;
+        
+        msg!("Channel pricing initialized");
+        Ok(())
+    }
+
+    /// Set the price for every channel in `channel_mask` (0 = use default price)
+    pub fn set_channel_price(
+        ctx: Context<SetChannelPrice>,
+        channel_mask: u32,
+        price: u64,
+    ) -> Result<()> {
+        require!(channel_mask != 0, ErrorCode::InvalidChannel);
+        
+        let pricing = &mut ctx.accounts.channel_pricing;
+        for (channel, slot) in pricing.prices.iter_mut().enumerate() {
+            if channel_mask & (1u32 << channel) != 0 {
+                *slot = price;
+            }
+        }
+        
+        emit!(ChannelPriceUpdated {
+            channel_mask,
+            price,
+        });
+        
+        msg!("Channel price set: mask={:#010x}, price={}", channel_mask, price);
+        Ok(())
+    }
+
     /// Create a new subscriber account and USDC vault
     pub fn create_subscriber(
         ctx: Context<CreateSubscriber>,
-        channels: Vec<u8>, // Bitmap of subscribed channels
+        channels: u32, // Bitmap of subscribed channel indices (see ChannelCatalog)
     ) -> Result<()> {
-        require!(channels.len() <= 4, ErrorCode::TooManyChannels);
+        require!(channels & !ctx.accounts.channel_catalog.active_mask == 0, ErrorCode::InvalidChannel);
         
         let subscriber = &mut ctx.accounts.subscriber;
         subscriber.owner = ctx.accounts.owner.key();
-        subscriber.channels = channels_to_u32(&channels);
+        subscriber.channels = channels;
         subscriber.balance = 0;
         subscriber.alerts_received = 0;
         subscriber.created_at = Clock::get()?.unix_timestamp;
@@ -114,37 +290,122 @@ pub mod subscription_registry {
     /// Update channel subscriptions
     pub fn update_channels(
         ctx: Context<UpdateChannels>,
-        channels: Vec<u8>,
+        channels: u32,
     ) -> Result<()> {
-        require!(channels.len() <= 4, ErrorCode::TooManyChannels);
+        require!(channels & !ctx.accounts.channel_catalog.active_mask == 0, ErrorCode::InvalidChannel);
         
         let subscriber = &mut ctx.accounts.subscriber;
-        subscriber.channels = channels_to_u32(&channels);
+        subscriber.channels = channels;
         
         msg!("Updated channels for subscriber {}", subscriber.owner);
         Ok(())
     }
 
+    /// Authorize a distributor key to charge subscribers (authority only)
+    pub fn add_distributor(
+        ctx: Context<AddDistributor>,
+        distributor: Pubkey,
+        max_charges_per_window: u32, // 0 = unlimited
+        window_seconds: i64,
+    ) -> Result<()> {
+        require!(window_seconds > 0, ErrorCode::InvalidRateLimit);
+        
+        let account = &mut ctx.accounts.distributor_account;
+        account.authority = distributor;
+        account.active = true;
+        account.max_charges_per_window = max_charges_per_window;
+        account.window_seconds = window_seconds;
+        account.window_start = 0;
+        account.window_charges = 0;
+        account.total_charges = 0;
+        account.added_at = Clock::get()?.unix_timestamp;
+        account.bump = ctx.bumps.distributor_account;
+        
+        msg!("Distributor added: {} (limit={}/{}s)", 
+            distributor, max_charges_per_window, window_seconds);
+        Ok(())
+    }
+
+    /// Update a distributor's rate limit (authority only)
+    pub fn set_distributor_limits(
+        ctx: Context<ManageDistributor>,
+        max_charges_per_window: u32,
+        window_seconds: i64,
+    ) -> Result<()> {
+        require!(window_seconds > 0, ErrorCode::InvalidRateLimit);
+        
+        let account = &mut ctx.accounts.distributor_account;
+        account.max_charges_per_window = max_charges_per_window;
+        account.window_seconds = window_seconds;
+        account.window_start = 0;
+        account.window_charges = 0;
+        
+        msg!("Distributor {} limit set to {}/{}s", 
+            account.authority, max_charges_per_window, window_seconds);
+        Ok(())
+    }
+
+    /// Suspend a distributor (authority only)
+    pub fn suspend_distributor(ctx: Context<ManageDistributor>) -> Result<()> {
+        let account = &mut ctx.accounts.distributor_account;
+        account.active = false;
+        
+        msg!("Distributor suspended: {}", account.authority);
+        Ok(())
+    }
+
+    /// Resume a suspended distributor (authority only)
+    pub fn resume_distributor(ctx: Context<ManageDistributor>) -> Result<()> {
+        let account = &mut ctx.accounts.distributor_account;
+        account.active = true;
+        
+        msg!("Distributor resumed: {}", account.authority);
+        Ok(())
+    }
+
+    /// Remove a distributor and reclaim its rent (authority only)
+    pub fn remove_distributor(ctx: Context<RemoveDistributor>) -> Result<()> {
+        msg!("Distributor removed: {}", ctx.accounts.distributor_account.authority);
+        Ok(())
+    }
+
     /// Charge subscriber for alert delivery (called by authorized distributor)
+    ///
+    /// Moves the alert price out of the subscriber vault: the treasury fee goes to
+    /// the protocol treasury and the remainder to the publisher revenue pool, where
+    /// the publisher share is paid out and the infrastructure share is retained.
     pub fn charge_for_alert(
         ctx: Context<ChargeForAlert>,
         alert_hash: [u8; 32],
+        channel: u8,
     ) -> Result<()> {
+        require!(channel < 32, ErrorCode::InvalidChannel);
+        
         let config = &ctx.accounts.config;
         let subscriber = &mut ctx.accounts.subscriber;
+        let total_amount = channel_price(&ctx.accounts.channel_pricing, channel, config.price_per_alert);
         
         require!(subscriber.active, ErrorCode::SubscriberInactive);
-        require!(subscriber.balance >= config.price_per_alert, ErrorCode::InsufficientBalance);
+        require!(subscriber.channels & (1u32 << channel) != 0, ErrorCode::ChannelNotSubscribed);
+        require!(subscriber.balance >= total_amount, ErrorCode::InsufficientBalance);
+        
+        let now = Clock::get()?.unix_timestamp;
+        consume_rate_limit(&mut ctx.accounts.distributor_account, now, 1)?;
         
         // Calculate fees
-        let total_amount = config.price_per_alert;
-        let treasury_fee = (total_amount as u128)
-            .checked_mul(config.treasury_fee_bps as u128)
-            .ok_or(ErrorCode::Overflow)?
-            .checked_div(10000)
-            .ok_or(ErrorCode::Overflow)? as u64;
-        let publisher_amount = total_amount.checked_sub(treasury_fee)
-            .ok_or(ErrorCode::Overflow)?;
+        let (treasury_fee, publisher_amount) = split_payment(total_amount, config.treasury_fee_bps)?;
+        
+        // Settle from the subscriber vault
+        settle_from_vault(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.subscriber_vault.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.revenue_pool.to_account_info(),
+            &subscriber.owner,
+            subscriber.vault_bump,
+            treasury_fee,
+            publisher_amount,
+        )?;
         
         // Deduct from subscriber
         subscriber.balance = subscriber.balance.checked_sub(total_amount)
@@ -156,7 +417,7 @@ pub mod subscription_registry {
         delivery.subscriber = subscriber.key();
         delivery.alert_hash = alert_hash;
         delivery.amount_charged = total_amount;
-        delivery.timestamp = Clock::get()?.unix_timestamp;
+        delivery.timestamp = now;
         delivery.bump = ctx.bumps.delivery_receipt;
         
         // Update global stats
@@ -165,7 +426,159 @@ pub mod subscription_registry {
         config.total_revenue = config.total_revenue.checked_add(total_amount)
             .ok_or(ErrorCode::Overflow)?;
         
-        msg!("Charged {} for alert {:?}", subscriber.owner, &alert_hash[..8]);
+        msg!("Charged {} for alert {:?} on channel {}: treasury={}, publisher_pool={}",
+            subscriber.owner, &alert_hash[..8], channel, treasury_fee, publisher_amount);
+        Ok(())
+    }
+
+    /// Charge many subscribers for one alert (called by authorized distributor)
+    ///
+    /// Subscribers are passed as `(subscriber, subscriber_vault,
+    /// delivery_receipt)` triples in `remaining_accounts`. Each charged entry
+    /// initializes the same `["delivery", subscriber, alert_hash]` receipt as
+    /// `charge_for_alert`. Entries that were already charged for the alert,
+    /// or are inactive, underfunded or not subscribed to `channel`, are
+    /// skipped; every entry emits a result event.
+    pub fn charge_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ChargeBatch<'info>>,
+        alert_hash: [u8; 32],
+        batch_index: u32,
+        channel: u8,
+    ) -> Result<()> {
+        require!(channel < 32, ErrorCode::InvalidChannel);
+        let entries = ctx.remaining_accounts.chunks_exact(3);
+        require!(
+            entries.len() > 0 && entries.remainder().is_empty(),
+            ErrorCode::InvalidBatchAccounts
+        );
+        
+        let config = &ctx.accounts.config;
+        let total_amount = channel_price(&ctx.accounts.channel_pricing, channel, config.price_per_alert);
+        let (treasury_fee, publisher_amount) = split_payment(total_amount, config.treasury_fee_bps)?;
+        
+        let token_program = ctx.accounts.token_program.to_account_info();
+        let treasury = ctx.accounts.treasury.to_account_info();
+        let revenue_pool = ctx.accounts.revenue_pool.to_account_info();
+        let distributor = ctx.accounts.distributor.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+        let batch_key = ctx.accounts.batch_receipt.key();
+        let now = Clock::get()?.unix_timestamp;
+        
+        let mut seen: Vec<Pubkey> = Vec::with_capacity(entries.len());
+        let mut subscribers_hash = [0u8; 32];
+        let mut charged_count: u16 = 0;
+        let mut skipped_count: u16 = 0;
+        
+        for entry in entries {
+            let (subscriber_info, vault_info, receipt_info) = (&entry[0], &entry[1], &entry[2]);
+            require!(
+                subscriber_info.is_writable && vault_info.is_writable && receipt_info.is_writable,
+                ErrorCode::InvalidBatchAccounts
+            );
+            require!(!seen.contains(subscriber_info.key), ErrorCode::DuplicateSubscriber);
+            seen.push(*subscriber_info.key);
+            
+            let mut subscriber = Account::<Subscriber>::try_from(subscriber_info)?;
+            let expected_subscriber = Pubkey::create_program_address(
+                &[b"subscriber", subscriber.owner.as_ref(), &[subscriber.bump]],
+                ctx.program_id,
+            ).map_err(|_| ErrorCode::InvalidBatchAccounts)?;
+            let expected_vault = Pubkey::create_program_address(
+                &[b"subscriber_vault", subscriber.owner.as_ref(), &[subscriber.vault_bump]],
+                ctx.program_id,
+            ).map_err(|_| ErrorCode::InvalidBatchAccounts)?;
+            require_keys_eq!(expected_subscriber, subscriber_info.key(), ErrorCode::InvalidBatchAccounts);
+            require_keys_eq!(expected_vault, vault_info.key(), ErrorCode::InvalidBatchAccounts);
+            let (expected_receipt, receipt_bump) = Pubkey::find_program_address(
+                &[b"delivery", subscriber_info.key.as_ref(), alert_hash.as_ref()],
+                ctx.program_id,
+            );
+            require_keys_eq!(expected_receipt, receipt_info.key(), ErrorCode::InvalidBatchAccounts);
+            
+            let status = if receipt_info.owner == ctx.program_id {
+                ChargeStatus::AlreadyCharged
+            } else if subscriber.channels & (1u32 << channel) == 0 {
+                ChargeStatus::ChannelMismatch
+            } else if !subscriber.active {
+                ChargeStatus::Inactive
+            } else if subscriber.balance < total_amount {
+                ChargeStatus::InsufficientBalance
+            } else {
+                ChargeStatus::Charged
+            };
+            
+            if status == ChargeStatus::Charged {
+                settle_from_vault(
+                    &token_program,
+                    vault_info,
+                    &treasury,
+                    &revenue_pool,
+                    &subscriber.owner,
+                    subscriber.vault_bump,
+                    treasury_fee,
+                    publisher_amount,
+                )?;
+                
+                subscriber.balance = subscriber.balance.checked_sub(total_amount)
+                    .ok_or(ErrorCode::Overflow)?;
+                subscriber.alerts_received += 1;
+                subscriber.exit(ctx.program_id)?;
+                
+                init_delivery_receipt(
+                    receipt_info,
+                    &distributor,
+                    &system_program,
+                    ctx.program_id,
+                    &[b"delivery", subscriber_info.key.as_ref(), alert_hash.as_ref(), &[receipt_bump]],
+                    DeliveryReceipt {
+                        subscriber: subscriber_info.key(),
+                        alert_hash,
+                        amount_charged: total_amount,
+                        timestamp: now,
+                        bump: receipt_bump,
+                    },
+                )?;
+                
+                subscribers_hash = hashv(&[&subscribers_hash, subscriber_info.key.as_ref()]).to_bytes();
+                charged_count += 1;
+            } else {
+                skipped_count += 1;
+            }
+            
+            emit!(BatchChargeResult {
+                batch: batch_key,
+                subscriber: subscriber_info.key(),
+                status,
+                amount: if status == ChargeStatus::Charged { total_amount } else { 0 },
+            });
+        }
+        
+        consume_rate_limit(&mut ctx.accounts.distributor_account, now, charged_count as u32)?;
+        
+        let total_charged = total_amount.checked_mul(charged_count as u64)
+            .ok_or(ErrorCode::Overflow)?;
+        
+        // Record batch
+        let batch = &mut ctx.accounts.batch_receipt;
+        batch.alert_hash = alert_hash;
+        batch.batch_index = batch_index;
+        batch.channel = channel;
+        batch.distributor = ctx.accounts.distributor.key();
+        batch.charged_count = charged_count;
+        batch.skipped_count = skipped_count;
+        batch.total_charged = total_charged;
+        batch.subscribers_hash = subscribers_hash;
+        batch.timestamp = now;
+        batch.bump = ctx.bumps.batch_receipt;
+        
+        // Update global stats
+        let config = &mut ctx.accounts.config;
+        config.total_alerts_delivered += charged_count as u64;
+        config.total_revenue = config.total_revenue.checked_add(total_charged)
+            .ok_or(ErrorCode::Overflow)?;
+        
+        msg!("Batch {} for alert {:?}: charged={}, skipped={}", 
+            batch_index, &alert_hash[..8], charged_count, skipped_count);
         Ok(())
     }
 
@@ -201,6 +614,39 @@ pub struct ProtocolConfig {
     pub total_alerts_delivered: u64,
     pub total_revenue: u64,
     pub bump: u8,
+    pub pending_authority: Pubkey,  // Proposed authority (default = none)
+}
+
+/// Channel name registry. A channel's position in `channels` is its bit
+/// index in subscriber bitmaps and pricing tables.
+#[account]
+pub struct ChannelCatalog {
+    pub channels: Vec<ChannelEntry>,    // Max 32 entries
+    pub active_mask: u32,               // Bitmap of non-retired channels
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ChannelEntry {
+    pub name: String,                   // Max 32 chars, e.g. "regulatory/sec"
+    pub active: bool,
+}
+
+impl ChannelCatalog {
+    /// Bit index of an active channel by name
+    pub fn active_index(&self, name: &str) -> Option<u8> {
+        self.channels.iter()
+            .position(|entry| entry.active && entry.name == name)
+            .map(|index| index as u8)
+    }
+}
+
+/// Per-channel alert prices, indexed by channel bit. A zero entry falls
+/// back to `ProtocolConfig.price_per_alert`.
+#[account]
+pub struct ChannelPricing {
+    pub prices: [u64; 32],
+    pub bump: u8,
 }
 
 #[account]
@@ -215,6 +661,19 @@ pub struct Subscriber {
     pub vault_bump: u8,             // Bump for subscriber_vault PDA
 }
 
+#[account]
+pub struct Distributor {
+    pub authority: Pubkey,          // Distributor signing key
+    pub active: bool,
+    pub max_charges_per_window: u32, // 0 = unlimited
+    pub window_seconds: i64,
+    pub window_start: i64,
+    pub window_charges: u32,
+    pub total_charges: u64,
+    pub added_at: i64,
+    pub bump: u8,
+}
+
 #[account]
 pub struct DeliveryReceipt {
     pub subscriber: Pubkey,
@@ -224,6 +683,88 @@ pub struct DeliveryReceipt {
     pub bump: u8,
 }
 
+/// Compact record of a batch charge. `subscribers_hash` is a running
+/// SHA256 over the charged subscriber keys, in order.
+#[account]
+pub struct BatchReceipt {
+    pub alert_hash: [u8; 32],
+    pub batch_index: u32,
+    pub channel: u8,
+    pub distributor: Pubkey,
+    pub charged_count: u16,
+    pub skipped_count: u16,
+    pub total_charged: u64,
+    pub subscribers_hash: [u8; 32],
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+// === Events ===
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ChargeStatus {
+    Charged,
+    ChannelMismatch,
+    Inactive,
+    InsufficientBalance,
+    AlreadyCharged,
+}
+
+#[event]
+pub struct PriceUpdated {
+    pub old_price: u64,
+    pub new_price: u64,
+}
+
+#[event]
+pub struct TreasuryFeeUpdated {
+    pub old_fee_bps: u16,
+    pub new_fee_bps: u16,
+}
+
+#[event]
+pub struct TreasuryUpdated {
+    pub old_treasury: Pubkey,
+    pub new_treasury: Pubkey,
+}
+
+#[event]
+pub struct ChannelAdded {
+    pub index: u8,
+    pub name: String,
+}
+
+#[event]
+pub struct ChannelRetired {
+    pub index: u8,
+}
+
+#[event]
+pub struct ChannelPriceUpdated {
+    pub channel_mask: u32,
+    pub price: u64,
+}
+
+#[event]
+pub struct AuthorityProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct BatchChargeResult {
+    pub batch: Pubkey,
+    pub subscriber: Pubkey,
+    pub status: ChargeStatus,
+    pub amount: u64,
+}
+
 // === Contexts ===
 
 #[derive(Accounts)]
@@ -231,7 +772,7 @@ pub struct Initialize<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + 32 + 32 + 32 + 8 + 2 + 8 + 8 + 8 + 1,
+        space = 8 + 32 + 32 + 32 + 8 + 2 + 8 + 8 + 8 + 1 + 32,
         seeds = [b"config"],
         bump
     )]
@@ -243,12 +784,159 @@ pub struct Initialize<'info> {
     /// CHECK: Treasury token account
     pub treasury: AccountInfo<'info>,
     
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = publisher_registry.bump,
+        seeds::program = publisher_registry::ID
+    )]
+    pub publisher_registry: Account<'info, PublisherRegistry>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    /// New treasury token account (optional, must hold USDC)
+    #[account(
+        constraint = treasury.mint == config.usdc_mint @ ErrorCode::InvalidMint
+    )]
+    pub treasury: Option<Account<'info, TokenAccount>>,
+    
+    /// Required when changing the treasury fee, to check the fee split
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = publisher_registry.bump,
+        seeds::program = publisher_registry::ID
+    )]
+    pub publisher_registry: Option<Account<'info, PublisherRegistry>>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.pending_authority != Pubkey::default() @ ErrorCode::NoPendingAuthority,
+        constraint = config.pending_authority == pending_authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    pub pending_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeChannelCatalog<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 4 + 32 * (4 + 32 + 1) + 4 + 1,
+        seeds = [b"channel_catalog"],
+        bump
+    )]
+    pub channel_catalog: Account<'info, ChannelCatalog>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageChannelCatalog<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"channel_catalog"],
+        bump = channel_catalog.bump
+    )]
+    pub channel_catalog: Account<'info, ChannelCatalog>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeChannelPricing<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 8 * 32 + 1,
+        seeds = [b"channel_pricing"],
+        bump
+    )]
+    pub channel_pricing: Account<'info, ChannelPricing>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct SetChannelPrice<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"channel_pricing"],
+        bump = channel_pricing.bump
+    )]
+    pub channel_pricing: Account<'info, ChannelPricing>,
+    
+    pub authority: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct CreateSubscriber<'info> {
     #[account(
@@ -258,6 +946,12 @@ pub struct CreateSubscriber<'info> {
     )]
     pub config: Account<'info, ProtocolConfig>,
     
+    #[account(
+        seeds = [b"channel_catalog"],
+        bump = channel_catalog.bump
+    )]
+    pub channel_catalog: Account<'info, ChannelCatalog>,
+    
     #[account(
         init,
         payer = owner,
@@ -352,10 +1046,82 @@ pub struct UpdateChannels<'info> {
     )]
     pub subscriber: Account<'info, Subscriber>,
     
+    #[account(
+        seeds = [b"channel_catalog"],
+        bump = channel_catalog.bump
+    )]
+    pub channel_catalog: Account<'info, ChannelCatalog>,
+    
     pub owner: Signer<'info>,
 }
 
 #[derive(Accounts)]
+#[instruction(distributor: Pubkey)]
+pub struct AddDistributor<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32 + 1 + 4 + 8 + 8 + 4 + 8 + 8 + 1,
+        seeds = [b"distributor", distributor.as_ref()],
+        bump
+    )]
+    pub distributor_account: Account<'info, Distributor>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageDistributor<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"distributor", distributor_account.authority.as_ref()],
+        bump = distributor_account.bump
+    )]
+    pub distributor_account: Account<'info, Distributor>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveDistributor<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"distributor", distributor_account.authority.as_ref()],
+        bump = distributor_account.bump
+    )]
+    pub distributor_account: Account<'info, Distributor>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(alert_hash: [u8; 32], channel: u8)]
 pub struct ChargeForAlert<'info> {
     #[account(
         mut,
@@ -364,22 +1130,140 @@ pub struct ChargeForAlert<'info> {
     )]
     pub config: Account<'info, ProtocolConfig>,
     
-    #[account(mut)]
+    #[account(
+        seeds = [b"channel_pricing"],
+        bump = channel_pricing.bump
+    )]
+    pub channel_pricing: Account<'info, ChannelPricing>,
+    
+    #[account(
+        mut,
+        seeds = [b"subscriber", subscriber.owner.as_ref()],
+        bump = subscriber.bump
+    )]
     pub subscriber: Account<'info, Subscriber>,
     
+    #[account(
+        mut,
+        seeds = [b"subscriber_vault", subscriber.owner.as_ref()],
+        bump = subscriber.vault_bump
+    )]
+    pub subscriber_vault: Account<'info, TokenAccount>,
+    
+    /// Protocol treasury (must match config.treasury)
+    #[account(
+        mut,
+        address = config.treasury @ ErrorCode::InvalidTreasury,
+        constraint = treasury.mint == config.usdc_mint @ ErrorCode::InvalidMint
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = publisher_registry.bump,
+        seeds::program = publisher_registry::ID
+    )]
+    pub publisher_registry: Account<'info, PublisherRegistry>,
+    
+    /// Publisher registry revenue pool
+    #[account(
+        mut,
+        seeds = [b"revenue_pool", publisher_registry.key().as_ref()],
+        bump,
+        seeds::program = publisher_registry::ID,
+        constraint = revenue_pool.mint == config.usdc_mint @ ErrorCode::InvalidMint
+    )]
+    pub revenue_pool: Account<'info, TokenAccount>,
+    
+    /// One receipt per (subscriber, alert), so an alert can never be charged twice
     #[account(
         init,
         payer = distributor,
-        space = 8 + 32 + 32 + 8 + 8 + 1,
-        seeds = [b"delivery", subscriber.key().as_ref(), &Clock::get()?.unix_timestamp.to_le_bytes()],
+        space = DELIVERY_RECEIPT_SPACE,
+        seeds = [b"delivery", subscriber.key().as_ref(), alert_hash.as_ref()],
         bump
     )]
     pub delivery_receipt: Account<'info, DeliveryReceipt>,
     
     /// Authorized distributor (protocol-controlled)
+    #[account(
+        mut,
+        seeds = [b"distributor", distributor.key().as_ref()],
+        bump = distributor_account.bump,
+        constraint = distributor_account.active @ ErrorCode::DistributorInactive
+    )]
+    pub distributor_account: Account<'info, Distributor>,
+    
+    #[account(mut)]
+    pub distributor: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(alert_hash: [u8; 32], batch_index: u32)]
+pub struct ChargeBatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        seeds = [b"channel_pricing"],
+        bump = channel_pricing.bump
+    )]
+    pub channel_pricing: Account<'info, ChannelPricing>,
+    
+    /// Protocol treasury (must match config.treasury)
+    #[account(
+        mut,
+        address = config.treasury @ ErrorCode::InvalidTreasury,
+        constraint = treasury.mint == config.usdc_mint @ ErrorCode::InvalidMint
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"publisher_registry"],
+        bump = publisher_registry.bump,
+        seeds::program = publisher_registry::ID
+    )]
+    pub publisher_registry: Account<'info, PublisherRegistry>,
+    
+    /// Publisher registry revenue pool
+    #[account(
+        mut,
+        seeds = [b"revenue_pool", publisher_registry.key().as_ref()],
+        bump,
+        seeds::program = publisher_registry::ID,
+        constraint = revenue_pool.mint == config.usdc_mint @ ErrorCode::InvalidMint
+    )]
+    pub revenue_pool: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = distributor,
+        space = 8 + 32 + 4 + 1 + 32 + 2 + 2 + 8 + 32 + 8 + 1,
+        seeds = [b"batch", alert_hash.as_ref(), &batch_index.to_le_bytes()],
+        bump
+    )]
+    pub batch_receipt: Account<'info, BatchReceipt>,
+    
+    /// Authorized distributor (protocol-controlled)
+    #[account(
+        mut,
+        seeds = [b"distributor", distributor.key().as_ref()],
+        bump = distributor_account.bump,
+        constraint = distributor_account.active @ ErrorCode::DistributorInactive
+    )]
+    pub distributor_account: Account<'info, Distributor>,
+    
     #[account(mut)]
     pub distributor: Signer<'info>,
     
+    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
@@ -411,12 +1295,134 @@ pub struct Reactivate<'info> {
 
 // === Helpers ===
 
-fn channels_to_u32(channels: &[u8]) -> u32 {
-    let mut result: u32 = 0;
-    for (i, &byte) in channels.iter().enumerate() {
-        result |= (byte as u32) << (i * 8);
+/// Price of an alert on `channel`, falling back to the protocol default.
+fn channel_price(pricing: &ChannelPricing, channel: u8, default_price: u64) -> u64 {
+    match pricing.prices[channel as usize] {
+        0 => default_price,
+        price => price,
     }
-    result
+}
+
+/// Split an alert price into (treasury fee, publisher pool amount).
+fn split_payment(total_amount: u64, treasury_fee_bps: u16) -> Result<(u64, u64)> {
+    let treasury_fee = (total_amount as u128)
+        .checked_mul(treasury_fee_bps as u128)
+        .ok_or(ErrorCode::Overflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::Overflow)? as u64;
+    let publisher_amount = total_amount.checked_sub(treasury_fee)
+        .ok_or(ErrorCode::Overflow)?;
+    Ok((treasury_fee, publisher_amount))
+}
+
+/// Require the treasury fee plus the publisher share to fit in one charge.
+fn check_fee_split(treasury_fee_bps: u16, publisher_registry: &PublisherRegistry) -> Result<()> {
+    require!(
+        treasury_fee_bps as u32 + publisher_registry.publisher_share_bps as u32 <= 10000,
+        ErrorCode::InvalidFeeBps
+    );
+    Ok(())
+}
+
+/// Transfer a settled charge out of a subscriber vault, signing as the vault PDA.
+#[allow(clippy::too_many_arguments)]
+fn settle_from_vault<'info>(
+    token_program: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    treasury: &AccountInfo<'info>,
+    revenue_pool: &AccountInfo<'info>,
+    owner: &Pubkey,
+    vault_bump: u8,
+    treasury_fee: u64,
+    publisher_amount: u64,
+) -> Result<()> {
+    let seeds = &[
+        b"subscriber_vault",
+        owner.as_ref(),
+        &[vault_bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    for (to, amount) in [(treasury, treasury_fee), (revenue_pool, publisher_amount)] {
+        if amount == 0 {
+            continue;
+        }
+        let cpi_accounts = Transfer {
+            from: vault.clone(),
+            to: to.clone(),
+            authority: vault.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(token_program.clone(), cpi_accounts, signer);
+        transfer(cpi_ctx, amount)?;
+    }
+    Ok(())
+}
+
+/// Create a delivery receipt PDA at `receipt` (paid by `payer`) and write
+/// `data` into it. Tolerates lamports pre-funded to the address.
+fn init_delivery_receipt<'info>(
+    receipt: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    seeds: &[&[u8]],
+    data: DeliveryReceipt,
+) -> Result<()> {
+    let signer = &[seeds];
+    let rent = Rent::get()?.minimum_balance(DELIVERY_RECEIPT_SPACE);
+    
+    if receipt.lamports() == 0 {
+        let cpi_accounts = system_program::CreateAccount {
+            from: payer.clone(),
+            to: receipt.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(system_program.clone(), cpi_accounts, signer);
+        system_program::create_account(cpi_ctx, rent, DELIVERY_RECEIPT_SPACE as u64, program_id)?;
+    } else {
+        let top_up = rent.saturating_sub(receipt.lamports());
+        if top_up > 0 {
+            let cpi_accounts = system_program::Transfer {
+                from: payer.clone(),
+                to: receipt.clone(),
+            };
+            let cpi_ctx = CpiContext::new(system_program.clone(), cpi_accounts);
+            system_program::transfer(cpi_ctx, top_up)?;
+        }
+        let cpi_accounts = system_program::Allocate {
+            account_to_allocate: receipt.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(system_program.clone(), cpi_accounts, signer);
+        system_program::allocate(cpi_ctx, DELIVERY_RECEIPT_SPACE as u64)?;
+        let cpi_accounts = system_program::Assign {
+            account_to_assign: receipt.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(system_program.clone(), cpi_accounts, signer);
+        system_program::assign(cpi_ctx, program_id)?;
+    }
+    
+    let mut buffer = receipt.try_borrow_mut_data()?;
+    data.try_serialize(&mut &mut buffer[..])?;
+    Ok(())
+}
+
+/// Count `charges` against the distributor's rolling window, rejecting
+/// the call if it would exceed the configured limit.
+fn consume_rate_limit(distributor: &mut Distributor, now: i64, charges: u32) -> Result<()> {
+    if now.saturating_sub(distributor.window_start) >= distributor.window_seconds {
+        distributor.window_start = now;
+        distributor.window_charges = 0;
+    }
+    
+    let window_charges = distributor.window_charges.checked_add(charges)
+        .ok_or(ErrorCode::Overflow)?;
+    if distributor.max_charges_per_window > 0 {
+        require!(window_charges <= distributor.max_charges_per_window, ErrorCode::RateLimitExceeded);
+    }
+    
+    distributor.window_charges = window_charges;
+    distributor.total_charges = distributor.total_charges.checked_add(charges as u64)
+        .ok_or(ErrorCode::Overflow)?;
+    Ok(())
 }
 
 // === Errors ===
@@ -437,4 +1443,30 @@ pub enum ErrorCode {
     Unauthorized,
     #[msg("Invalid USDC mint")]
     InvalidMint,
+    #[msg("Invalid treasury account")]
+    InvalidTreasury,
+    #[msg("Distributor is not active")]
+    DistributorInactive,
+    #[msg("Distributor rate limit exceeded")]
+    RateLimitExceeded,
+    #[msg("Invalid rate limit window")]
+    InvalidRateLimit,
+    #[msg("Invalid channel index (must be 0-31)")]
+    InvalidChannel,
+    #[msg("Invalid batch accounts")]
+    InvalidBatchAccounts,
+    #[msg("Duplicate subscriber in batch")]
+    DuplicateSubscriber,
+    #[msg("Invalid fee (treasury fee plus publisher share max 10000 bps)")]
+    InvalidFeeBps,
+    #[msg("No pending authority")]
+    NoPendingAuthority,
+    #[msg("Subscriber is not subscribed to this channel")]
+    ChannelNotSubscribed,
+    #[msg("Invalid channel name (1-32 chars)")]
+    InvalidChannelName,
+    #[msg("Channel already exists")]
+    ChannelExists,
+    #[msg("Publisher registry account required to change the treasury fee")]
+    PublisherRegistryRequired,
 }
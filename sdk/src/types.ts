@@ -4,6 +4,8 @@
  * TypeScript types for alerts, subscriptions, and on-chain structures.
  */
 
+import { createHash } from 'node:crypto';
+
 // === Alert Types ===
 
 export interface Alert {
@@ -206,10 +208,16 @@ export function getPublisherSeeds(owner: string): Buffer[] {
 }
 
 /**
- * Derive PDA seeds for alert account
+ * Derive PDA seeds for alert account: `["alert", identity, sha256(alertId)]`.
+ * `identity` is the 32-byte publisher identity (the `Publisher.identity`
+ * key, e.g. `publicKey.toBytes()`), which stays fixed across ownership transfers.
  */
-export function getAlertSeeds(alertId: string): Buffer[] {
-  return [Buffer.from('alert'), Buffer.from(alertId)];
+export function getAlertSeeds(identity: Uint8Array, alertId: string): Buffer[] {
+  return [
+    Buffer.from('alert'),
+    Buffer.from(identity),
+    createHash('sha256').update(alertId).digest(),
+  ];
 }
 
 // === Price Constants ===